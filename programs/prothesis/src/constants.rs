@@ -10,11 +10,65 @@ pub const MEMBER_SEED: &[u8] = b"member";
 /// Seed prefix for proposal PDAs
 pub const PROPOSAL_SEED: &[u8] = b"proposal";
 
+/// Seed prefix for proposal sponsorship PDAs
+pub const SPONSORSHIP_SEED: &[u8] = b"sponsorship";
+
 /// Seed prefix for vote PDAs
 pub const VOTE_SEED: &[u8] = b"vote";
 
+/// Seed prefix for ballot PDAs of multiple-choice and ranked-choice proposals
+pub const BALLOT_SEED: &[u8] = b"ballot";
+
+/// Seed prefix for vote delegation PDAs
+pub const DELEGATION_SEED: &[u8] = b"delegation";
+
+/// Seed prefix for the token account holding the governance tokens deposited to a DAO
+pub const GOVERNANCE_VAULT_SEED: &[u8] = b"governance_vault";
+
+/// Seed prefix for governance token deposit PDAs
+pub const GOVERNANCE_DEPOSIT_SEED: &[u8] = b"governance_deposit";
+
+/// Seed prefix for vote commitment PDAs
+pub const VOTE_COMMITMENT_SEED: &[u8] = b"vote_commitment";
+
+/// Seed prefix for voice credit ledger PDAs
+pub const VOICE_CREDITS_SEED: &[u8] = b"voice_credits";
+
+/// Seed prefix for council veto PDAs
+pub const VETO_SEED: &[u8] = b"veto";
+
+/// Seed prefix for funding approval PDAs
+pub const FUNDING_APPROVAL_SEED: &[u8] = b"funding_approval";
+
+/// Seed prefix for proposal receipt PDAs
+pub const PROPOSAL_RECEIPT_SEED: &[u8] = b"proposal_receipt";
+
+/// Seed prefix for proposal deposit escrow PDAs
+pub const PROPOSAL_DEPOSIT_SEED: &[u8] = b"proposal_deposit";
+
+/// Seed prefix for role operation receipt PDAs
+pub const ROLE_OP_RECEIPT_SEED: &[u8] = b"role_op_receipt";
+
 /// Maximum length for proposal titles
 pub const MAX_TITLE_LENGTH: usize = 64;
 
 /// Maximum length for proposal content
 pub const MAX_CONTENT_LENGTH: usize = 2048;
+
+/// Maximum length for the URI of off-chain proposal content
+pub const MAX_CONTENT_URI_LENGTH: usize = 200;
+
+/// Maximum length for the reason of a council veto
+pub const MAX_VETO_REASON_LENGTH: usize = 256;
+
+/// Maximum number of options of a multiple-choice or ranked-choice proposal
+pub const MAX_PROPOSAL_OPTIONS: usize = 8;
+
+/// Maximum length for proposal option labels
+pub const MAX_OPTION_LABEL_LENGTH: usize = 32;
+
+/// Fixed-point precision of conviction, so that small amounts of support still accrue conviction gradually
+pub const CONVICTION_PRECISION: u64 = 1_000_000;
+
+/// Domain prefix of the off-chain vote messages members sign for relayers, so the signatures cannot be reused elsewhere
+pub const RELAYED_VOTE_DOMAIN: &[u8] = b"prothesis:relayed_vote";
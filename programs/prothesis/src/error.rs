@@ -31,8 +31,8 @@ pub enum ProthesisError {
     #[msg("This cannot be resolved until it has been reviewed.")]
     CannotResolveBeforeReview,
 
-    /// Returned when the specified vote is neither 0 nor 1
-    #[msg("Invalid vote type: A vote must be either 1 for upvote or 0 for downvote")]
+    /// Returned when the specified vote is not 0, 1 or 2
+    #[msg("Invalid vote type: A vote must be 1 for upvote, 0 for downvote or 2 to abstain")]
     InvalidVoteType,
 
     /// Return when the role operation seed is invalid or not recognized.
@@ -58,4 +58,267 @@ pub enum ProthesisError {
     /// Not enough multisig signers provided to approve the action
     #[msg("Insufficient multisig signers to approve this action.")]
     InsufficientMultisigSigners,
+
+    /// A multisig account is not a member account of this DAO
+    #[msg("Account is not a valid member account of this DAO")]
+    InvalidMemberAccount,
+
+    /// The same member was provided more than once as a multisig signer
+    #[msg("Duplicate multisig signer")]
+    DuplicateSigner,
+
+    /// Returned when trying to approve or execute the funding of a proposal that is not approved
+    #[msg("Only approved proposals can be funded")]
+    ProposalNotApproved,
+
+    /// Returned when trying to resolve or fund a proposal that has already been funded
+    #[msg("This has already been executed")]
+    AlreadyExecuted,
+
+    /// The rent destination does not match the one configured for the DAO
+    #[msg("Rent destination does not match the DAO's configured rent destination")]
+    InvalidRentDestination,
+
+    /// The account voted on is neither a proposal nor a role operation
+    #[msg("Vote target must be a proposal or a role operation")]
+    InvalidVoteTarget,
+
+    /// Returned when trying to close a vote on a proposal or role operation that is still pending
+    #[msg("Votes can only be closed once voting has ended")]
+    VotingNotEnded,
+
+    /// The account provided is not the voter's vote on the given target
+    #[msg("Account is not the voter's vote account for the given target")]
+    InvalidVoteAccount,
+
+    /// Returned when a percentage is above 100%
+    #[msg("Percentages must not exceed 10000 basis points")]
+    InvalidPercentage,
+
+    /// Returned when a member who joined after a proposal or role operation was created tries to vote on it,
+    /// or a council member promoted after a council role operation was created
+    #[msg("Members who joined or were promoted to the council after voting started are not eligible to vote")]
+    VoterNotEligible,
+
+    /// Returned when changing a vote to the type it already has
+    #[msg("The vote already has this type")]
+    VoteUnchanged,
+
+    /// Returned when trying to change or retract a vote on a role operation that is not in Pending status
+    #[msg("Votes can only be changed on pending role operations")]
+    RoleOpNotPending,
+
+    /// Returned when a member tries to delegate their vote to themselves
+    #[msg("Members cannot delegate their vote to themselves")]
+    SelfDelegation,
+
+    /// The account provided is not a delegation to the voting delegate
+    #[msg("Account is not a delegation to this delegate")]
+    InvalidDelegation,
+
+    /// Returned when a governance token account does not match the DAO's governance mint
+    #[msg("Token account or mint does not match the DAO's governance mint")]
+    InvalidGovernanceMint,
+
+    /// Returned when a token-only action is attempted in a DAO without a governance mint, or the deposit is missing
+    #[msg("A governance token deposit is required in token-weighted DAOs")]
+    GovernanceDepositRequired,
+
+    /// Returned when voting in token-weighted mode without any deposited tokens
+    #[msg("No governance tokens deposited to vote with")]
+    NoVotingWeight,
+
+    /// Returned when voting on a token-weighted proposal after its voting period
+    #[msg("The voting period of this proposal has ended")]
+    VotingPeriodEnded,
+
+    /// Returned when withdrawing governance tokens that still back votes on pending proposals
+    #[msg("Governance tokens are locked until the proposals voted on end")]
+    GovernanceTokensLocked,
+
+    /// Returned when withdrawing more governance tokens than deposited
+    #[msg("Cannot withdraw more governance tokens than deposited")]
+    InsufficientGovernanceTokens,
+
+    /// Returned when a voice credit round of 0 seconds is configured
+    #[msg("Voice credit rounds must last at least one second")]
+    InvalidRoundDuration,
+
+    /// Returned when submitting a quadratic proposal in a DAO without voice credits
+    #[msg("Quadratic voting is not enabled for this DAO")]
+    QuadraticVotingDisabled,
+
+    /// Returned when casting a quadratic vote on a standard proposal or vice versa
+    #[msg("This kind of vote cannot be cast on this kind of proposal")]
+    WrongProposalKind,
+
+    /// Returned when a quadratic vote costs more voice credits than the member has left in the round
+    #[msg("Not enough voice credits left in this round")]
+    InsufficientVoiceCredits,
+
+    /// Returned when the voice credit ledger of the round is required but missing
+    #[msg("The voter's voice credit ledger of the proposal's round is required")]
+    VoiceCreditLedgerRequired,
+
+    /// Returned when a negative conviction half-life is configured
+    #[msg("The conviction half-life cannot be negative")]
+    InvalidHalfLife,
+
+    /// Returned when submitting a conviction proposal in a DAO without conviction voting
+    #[msg("Conviction voting is not enabled for this DAO")]
+    ConvictionVotingDisabled,
+
+    /// Returned when trying to oppose or abstain on a conviction proposal
+    #[msg("Conviction proposals can only be supported")]
+    ConvictionSupportOnly,
+
+    /// Returned when a negative reveal lifetime is configured
+    #[msg("The reveal lifetime cannot be negative")]
+    InvalidRevealLifetime,
+
+    /// Returned when voting directly on a proposal whose votes are committed and revealed
+    #[msg("Votes on this proposal must be committed and revealed")]
+    VotesMustBeCommitted,
+
+    /// Returned when committing a vote on a proposal that does not use commit-reveal voting
+    #[msg("This proposal does not use commit-reveal voting")]
+    CommitRevealNotEnabled,
+
+    /// Returned when revealing a vote outside of the reveal phase
+    #[msg("Votes can only be revealed during the reveal phase")]
+    NotInRevealPhase,
+
+    /// Returned when the revealed vote and salt do not match the commitment
+    #[msg("Revealed vote does not match the commitment")]
+    CommitmentMismatch,
+
+    /// Returned when reviewing a proposal, or closing a commitment, before the reveal phase has ended
+    #[msg("The reveal phase of this proposal has not ended")]
+    RevealPhaseNotEnded,
+
+    /// Returned when a relayed vote is not preceded by a valid ed25519 signature of its message by the voter
+    #[msg("The relayed vote is not signed by the voter")]
+    InvalidVoteSignature,
+
+    /// Returned when a relayed vote message does not carry the voter's next relay nonce
+    #[msg("The relayed vote nonce is invalid")]
+    InvalidRelayNonce,
+
+    /// Returned when a choice proposal has too few or too many options or requests funding outside of
+    /// them, or when another proposal has options
+    #[msg("Choice proposals need between 2 and 8 options and are funded through them, other proposals take no options")]
+    InvalidProposalOptions,
+
+    /// Returned when a proposal option label exceeds the maximum length
+    #[msg("Option label cannot be more than 32 characters")]
+    OptionLabelTooLong,
+
+    /// Returned when a ballot does not pick exactly one option, or ranks unknown or repeated options
+    #[msg("Invalid choices for this proposal")]
+    InvalidChoices,
+
+    /// Returned when reviewing a choice proposal before its voting period has ended
+    #[msg("The voting period of this proposal has not ended")]
+    VotingPeriodNotEnded,
+
+    /// Returned when a ballot passed to count a ranked-choice proposal is not one of its ballots,
+    /// or was already counted in the current instant-runoff round
+    #[msg("Ballots must be the proposal's and counted once per round")]
+    InvalidBallots,
+
+    /// Returned when a negative veto period is configured
+    #[msg("The veto period cannot be negative")]
+    InvalidVetoPeriod,
+
+    /// Returned when vetoing a proposal after its veto period, or in a DAO without one
+    #[msg("The veto period of this proposal has ended")]
+    VetoPeriodEnded,
+
+    /// Returned when funding an approved proposal before its veto period has elapsed
+    #[msg("The proposal cannot be funded before its veto period has elapsed")]
+    VetoPeriodNotEnded,
+
+    /// Returned when a veto reason exceeds the maximum length
+    #[msg("Veto reason cannot be more than 256 characters")]
+    VetoReasonTooLong,
+
+    /// Returned when a negative execution delay is configured
+    #[msg("The execution delay cannot be negative")]
+    InvalidExecutionDelay,
+
+    /// Returned when funding an approved proposal before its execution delay has elapsed
+    #[msg("The proposal cannot be funded before its execution delay has elapsed")]
+    ExecutionDelayNotElapsed,
+
+    /// Returned when a member other than the author tries to cancel, edit or open a proposal
+    #[msg("Only the author can manage this proposal")]
+    NotProposalAuthor,
+
+    /// Returned when editing or opening a proposal whose voting has already opened
+    #[msg("Only draft proposals can be edited or opened for voting")]
+    NotDraft,
+
+    /// Returned when reviewing a proposal that is still a draft
+    #[msg("Voting on this proposal has not opened yet")]
+    VotingNotOpen,
+
+    /// Returned when the author tries to sponsor their own proposal
+    #[msg("Authors cannot sponsor their own proposals")]
+    SelfSponsorship,
+
+    /// Returned when opening voting on a proposal without enough sponsors
+    #[msg("The proposal does not have enough sponsors to open voting")]
+    NotEnoughSponsors,
+
+    /// Returned when the sponsorship passed is not the sponsor's sponsorship of the proposal
+    #[msg("Account is not the sponsor's sponsorship of the given proposal")]
+    InvalidSponsorship,
+
+    /// Returned when the proposal deposit cannot keep the escrow account rent-exempt
+    #[msg("The proposal deposit must be 0 or at least the rent-exempt minimum")]
+    InvalidProposalDeposit,
+
+    /// Returned when the deposit recipient does not match the outcome of the proposal
+    #[msg("Deposit recipient does not match the outcome of the proposal")]
+    InvalidDepositRecipient,
+
+    /// Returned when the author already has the maximum number of open proposals
+    #[msg("The author has too many open proposals")]
+    TooManyOpenProposals,
+
+    /// Returned when the author submits a proposal before their cooldown has elapsed
+    #[msg("The author must wait for the proposal cooldown to elapse")]
+    ProposalCooldownActive,
+
+    /// Returned when the proposal cooldown is negative
+    #[msg("Proposal cooldown must not be negative")]
+    InvalidProposalCooldown,
+
+    /// Returned when the URI of off-chain proposal content is empty or too long
+    #[msg("Content URI is empty or exceeds maximum length")]
+    InvalidContentUri,
+
+    /// Returned when the proposal content does not match the hash expected by the resolver
+    #[msg("Proposal content does not match the expected hash")]
+    ContentHashMismatch,
+
+    /// Returned when editing a proposal that members have already sponsored
+    #[msg("Sponsored proposals cannot be edited")]
+    ProposalSponsored,
+
+    /// Returned when a member exits without passing every vote their delegate cast for them
+    #[msg("Every vote the delegate cast for the member must be passed to exit")]
+    DelegatedVotesOutstanding,
+
+    /// Returned when a vote's rent is refunded to an account other than the one that paid it
+    #[msg("The rent of a vote must be refunded to the account that paid it")]
+    InvalidRentPayer,
+
+    /// Returned when the funding approval passed is not the approver's approval of the given proposal
+    #[msg("Account is not the approver's funding approval of the given proposal")]
+    InvalidFundingApproval,
+
+    /// Returned when a veto threshold of 0 is configured
+    #[msg("The veto threshold must be above 0 basis points")]
+    InvalidVetoPct,
 }
@@ -0,0 +1,21 @@
+use anchor_lang::prelude::*;
+
+/// Emitted when the multisig signers of a proposal resolution have been verified
+#[event]
+pub struct MultisigSignersVerified {
+    /// The proposal being resolved
+    pub proposal: Pubkey,
+    /// Member accounts that were counted as signers, the resolver first
+    pub signers: Vec<Pubkey>,
+}
+
+/// Emitted when a council supermajority vetoes an approved proposal
+#[event]
+pub struct ProposalVetoed {
+    /// The vetoed proposal
+    pub proposal: Pubkey,
+    /// Number of council members who vetoed the proposal
+    pub vetoes: u64,
+    /// Reason given by the council member whose veto blocked the proposal
+    pub reason: String,
+}
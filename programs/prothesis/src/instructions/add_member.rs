@@ -46,6 +46,11 @@ impl<'info> AddMember<'info> {
             owner: self.aspirant.key(),
             is_council: false,
             joined_at: Clock::get()?.unix_timestamp,
+            council_since: 0,
+            relay_nonce: 0,
+            open_proposals: 0,
+            last_proposal_at: 0,
+            delegated_votes: 0,
             bump: bumps.new_member,
         });
 
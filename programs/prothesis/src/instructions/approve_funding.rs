@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{DAO_CONFIG_SEED, FUNDING_APPROVAL_SEED, MEMBER_SEED, PROPOSAL_SEED},
+    error::ProthesisError,
+    state::{DAOConfig, FundingApproval, Member, Proposal, Status},
+};
+
+#[derive(Accounts)]
+pub struct ApproveFunding<'info> {
+    #[account(mut)]
+    pub approver: Signer<'info>,
+
+    #[account(
+        seeds = [DAO_CONFIG_SEED, dao_config.id.to_le_bytes().as_ref()],
+        bump = dao_config.bump,
+    )]
+    pub dao_config: Account<'info, DAOConfig>,
+
+    #[account(
+        seeds = [MEMBER_SEED, approver.key().as_ref(), dao_config.key().as_ref()],
+        bump = approver_member.bump,
+        constraint = approver_member.is_council @ ProthesisError::NotCouncilMember
+    )]
+    pub approver_member: Account<'info, Member>,
+
+    #[account(
+        mut,
+        seeds = [PROPOSAL_SEED, proposal.id.to_le_bytes().as_ref(), dao_config.key().as_ref()],
+        bump = proposal.bump,
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    #[account(
+        init,
+        payer = approver,
+        seeds = [FUNDING_APPROVAL_SEED, approver_member.key().as_ref(), proposal.key().as_ref()],
+        bump,
+        space = FundingApproval::SPACE
+    )]
+    pub funding_approval: Account<'info, FundingApproval>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ApproveFunding<'info> {
+    pub fn approve_funding(&mut self, bumps: &ApproveFundingBumps) -> Result<()> {
+        require!(
+            self.proposal.status == Status::Approved,
+            ProthesisError::ProposalNotApproved
+        );
+
+        self.funding_approval.set_inner(FundingApproval {
+            approver: self.approver_member.key(),
+            proposal: self.proposal.key(),
+            approved_at: Clock::get()?.unix_timestamp,
+            bump: bumps.funding_approval,
+        });
+
+        self.proposal.funding_approvals = self
+            .proposal
+            .funding_approvals
+            .checked_add(1)
+            .ok_or(ProthesisError::CountOutOfRange)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{DAO_CONFIG_SEED, MEMBER_SEED, PROPOSAL_SEED},
+    error::ProthesisError,
+    state::{DAOConfig, Member, Proposal, Status},
+};
+
+#[derive(Accounts)]
+pub struct CancelProposal<'info> {
+    #[account(mut)]
+    pub author: Signer<'info>,
+
+    #[account(
+        seeds = [DAO_CONFIG_SEED, dao_config.id.to_le_bytes().as_ref()],
+        bump = dao_config.bump,
+    )]
+    pub dao_config: Account<'info, DAOConfig>,
+
+    #[account(
+        mut,
+        seeds = [MEMBER_SEED, author.key().as_ref(), dao_config.key().as_ref()],
+        bump = author_member.bump
+    )]
+    pub author_member: Account<'info, Member>,
+
+    #[account(
+        mut,
+        seeds = [PROPOSAL_SEED, proposal.id.to_le_bytes().as_ref(), dao_config.key().as_ref()],
+        bump = proposal.bump,
+        constraint = proposal.author == author_member.key() @ ProthesisError::NotProposalAuthor
+    )]
+    pub proposal: Account<'info, Proposal>,
+}
+
+impl<'info> CancelProposal<'info> {
+    /// Cancels a draft or pending proposal. Its votes become closable right away, and the proposal
+    /// itself is closed by `resolve_proposal` like any other proposal that was not funded.
+    pub fn cancel_proposal(&mut self) -> Result<()> {
+        require!(
+            matches!(self.proposal.status, Status::Draft | Status::Pending),
+            ProthesisError::ProposalNotPending
+        );
+
+        self.proposal.status = Status::Cancelled;
+        self.author_member.close_proposal();
+
+        Ok(())
+    }
+}
@@ -0,0 +1,267 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{
+        DAO_CONFIG_SEED, DELEGATION_SEED, GOVERNANCE_DEPOSIT_SEED, MEMBER_SEED, PROPOSAL_SEED,
+        VOTE_SEED,
+    },
+    error::ProthesisError,
+    state::{
+        DAOConfig, Delegation, GovernanceDeposit, Member, Proposal, ProposalKind, Status, Vote,
+    },
+    utils::create_pda_account,
+};
+
+#[derive(Accounts)]
+pub struct CastDelegatedVotes<'info> {
+    #[account(mut)]
+    pub delegate: Signer<'info>,
+
+    #[account(
+        seeds = [DAO_CONFIG_SEED, dao_config.id.to_le_bytes().as_ref()],
+        bump = dao_config.bump,
+    )]
+    pub dao_config: Account<'info, DAOConfig>,
+
+    #[account(
+        mut,
+        seeds = [PROPOSAL_SEED, proposal.id.to_le_bytes().as_ref(), dao_config.key().as_ref()],
+        bump = proposal.bump,
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    #[account(
+        seeds = [MEMBER_SEED, delegate.key().as_ref(), dao_config.key().as_ref()],
+        bump = delegate_member.bump
+    )]
+    pub delegate_member: Account<'info, Member>,
+
+    #[account(
+        seeds = [VOTE_SEED, delegate_member.key().as_ref(), proposal.key().as_ref()],
+        bump,
+    )]
+    pub delegate_vote: Account<'info, Vote>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CastDelegatedVotes<'info> {
+    /// Casts the delegate's vote for every delegator passed in the remaining accounts as
+    /// `[delegator_member, delegation, vote]` triples, followed by the delegator's governance
+    /// token deposit in token-weighted mode. Votes the delegators cast or changed themselves
+    /// are left untouched, and votes previously cast by the delegate are updated.
+    pub fn cast_delegated_votes(
+        &mut self,
+        remaining_accounts: Vec<AccountInfo<'info>>,
+    ) -> Result<()> {
+        require!(
+            self.proposal.status == Status::Pending,
+            ProthesisError::ProposalNotPending
+        );
+
+        // Voice credits are personal, so quadratic votes cannot be delegated
+        require!(
+            self.proposal.kind == ProposalKind::Standard,
+            ProthesisError::WrongProposalKind
+        );
+        // Neither can secret ballots, which the delegate's vote would give away
+        require!(
+            !self.dao_config.uses_commit_reveal(&self.proposal.kind),
+            ProthesisError::VotesMustBeCommitted
+        );
+
+        let token_weighted = self.dao_config.is_token_weighted();
+        let voting_deadline = self
+            .dao_config
+            .voting_deadline(self.proposal.voting_opened_at);
+        if token_weighted {
+            require!(
+                Clock::get()?.unix_timestamp < voting_deadline,
+                ProthesisError::VotingPeriodEnded
+            );
+        }
+
+        let delegators = remaining_accounts.chunks_exact(if token_weighted { 4 } else { 3 });
+        require!(
+            delegators.remainder().is_empty(),
+            ProthesisError::InvalidDelegation
+        );
+
+        let dao_config_key = self.dao_config.key();
+        let proposal_key = self.proposal.key();
+        let delegate_member_key = self.delegate_member.key();
+        let vote_type = self.delegate_vote.vote_type.clone();
+
+        for accounts in delegators {
+            let (delegator_member, delegation, vote) = (&accounts[0], &accounts[1], &accounts[2]);
+
+            // The delegator must still be a member of the DAO and part of the electorate snapshot
+            require_keys_eq!(
+                *delegator_member.owner,
+                crate::ID,
+                ProthesisError::InvalidMemberAccount
+            );
+            let mut member = Member::try_deserialize(&mut &delegator_member.try_borrow_data()?[..])
+                .map_err(|_| ProthesisError::InvalidMemberAccount)?;
+            let expected_member = Pubkey::create_program_address(
+                &[
+                    MEMBER_SEED,
+                    member.owner.as_ref(),
+                    dao_config_key.as_ref(),
+                    &[member.bump],
+                ],
+                &crate::ID,
+            )
+            .map_err(|_| ProthesisError::InvalidMemberAccount)?;
+            require_keys_eq!(
+                delegator_member.key(),
+                expected_member,
+                ProthesisError::InvalidMemberAccount
+            );
+            require!(
+                member.joined_at <= self.proposal.voting_opened_at,
+                ProthesisError::VoterNotEligible
+            );
+
+            // The delegation must be the delegator's, and made to this delegate
+            let (expected_delegation, _) = Pubkey::find_program_address(
+                &[
+                    DELEGATION_SEED,
+                    delegator_member.key().as_ref(),
+                    dao_config_key.as_ref(),
+                ],
+                &crate::ID,
+            );
+            require_keys_eq!(
+                delegation.key(),
+                expected_delegation,
+                ProthesisError::InvalidDelegation
+            );
+            require_keys_eq!(
+                *delegation.owner,
+                crate::ID,
+                ProthesisError::InvalidDelegation
+            );
+            let delegation_data =
+                Delegation::try_deserialize(&mut &delegation.try_borrow_data()?[..])
+                    .map_err(|_| ProthesisError::InvalidDelegation)?;
+            require_keys_eq!(
+                delegation_data.delegate,
+                delegate_member_key,
+                ProthesisError::InvalidDelegation
+            );
+
+            let (expected_vote, vote_bump) = Pubkey::find_program_address(
+                &[
+                    VOTE_SEED,
+                    delegator_member.key().as_ref(),
+                    proposal_key.as_ref(),
+                ],
+                &crate::ID,
+            );
+            require_keys_eq!(
+                vote.key(),
+                expected_vote,
+                ProthesisError::InvalidVoteAccount
+            );
+
+            let existing_vote = if vote.owner == &crate::ID {
+                let existing_vote = Vote::try_deserialize(&mut &vote.try_borrow_data()?[..])
+                    .map_err(|_| ProthesisError::InvalidVoteAccount)?;
+
+                // The delegator overrode the delegation by voting directly
+                if existing_vote.cast_by != Some(delegate_member_key) {
+                    continue;
+                }
+
+                Some(existing_vote)
+            } else {
+                None
+            };
+
+            let weight = if token_weighted {
+                let deposit = &accounts[3];
+                let (expected_deposit, _) = Pubkey::find_program_address(
+                    &[
+                        GOVERNANCE_DEPOSIT_SEED,
+                        member.owner.as_ref(),
+                        dao_config_key.as_ref(),
+                    ],
+                    &crate::ID,
+                );
+                require_keys_eq!(
+                    deposit.key(),
+                    expected_deposit,
+                    ProthesisError::GovernanceDepositRequired
+                );
+                require_keys_eq!(
+                    *deposit.owner,
+                    crate::ID,
+                    ProthesisError::GovernanceDepositRequired
+                );
+
+                // Lock the delegator's tokens just like a direct vote would
+                let mut deposit_data =
+                    GovernanceDeposit::try_deserialize(&mut &deposit.try_borrow_data()?[..])?;
+                require!(deposit_data.amount > 0, ProthesisError::NoVotingWeight);
+                if existing_vote.is_none() {
+                    deposit_data.lock_vote(voting_deadline)?;
+                } else {
+                    // Recasting an existing vote keeps the lock it already holds
+                    deposit_data.locked_until = deposit_data.locked_until.max(voting_deadline);
+                }
+                deposit_data.try_serialize(&mut &mut deposit.try_borrow_mut_data()?[..])?;
+
+                deposit_data.amount
+            } else {
+                1
+            };
+
+            if let Some(existing_vote) = existing_vote {
+                self.proposal
+                    .remove_vote(&existing_vote.vote_type, existing_vote.weight)?;
+            } else {
+                // First vote of the delegator on this proposal
+                let delegator_member_key = delegator_member.key();
+                let signer_seeds: &[&[&[u8]]] = &[&[
+                    VOTE_SEED,
+                    delegator_member_key.as_ref(),
+                    proposal_key.as_ref(),
+                    &[vote_bump],
+                ]];
+                create_pda_account(
+                    &self.delegate.to_account_info(),
+                    vote,
+                    &self.system_program,
+                    Vote::SPACE,
+                    signer_seeds,
+                )?;
+
+                self.proposal.voters_count = self
+                    .proposal
+                    .voters_count
+                    .checked_add(1)
+                    .ok_or(ProthesisError::CountOutOfRange)?;
+
+                // The delegator cannot exit while the vote is still counted
+                member.delegated_votes = member
+                    .delegated_votes
+                    .checked_add(1)
+                    .ok_or(ProthesisError::CountOutOfRange)?;
+                member.try_serialize(&mut &mut delegator_member.try_borrow_mut_data()?[..])?;
+            }
+
+            self.proposal.add_vote(&vote_type, weight)?;
+
+            let delegated_vote = Vote {
+                vote_type: vote_type.clone(),
+                weight,
+                cast_by: Some(delegate_member_key),
+                payer: self.delegate.key(),
+            };
+            delegated_vote.try_serialize(&mut &mut vote.try_borrow_mut_data()?[..])?;
+        }
+
+        Ok(())
+    }
+}
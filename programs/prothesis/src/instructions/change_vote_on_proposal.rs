@@ -0,0 +1,87 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{DAO_CONFIG_SEED, MEMBER_SEED, PROPOSAL_SEED, VOTE_SEED},
+    error::ProthesisError,
+    state::{DAOConfig, Member, Proposal, ProposalKind, Status, Vote, VoteType},
+};
+
+#[derive(Accounts)]
+pub struct ChangeVoteOnProposal<'info> {
+    pub voter: Signer<'info>,
+
+    #[account(
+        seeds = [DAO_CONFIG_SEED, dao_config.id.to_le_bytes().as_ref()],
+        bump = dao_config.bump,
+    )]
+    pub dao_config: Account<'info, DAOConfig>,
+
+    #[account(
+        mut,
+        seeds = [PROPOSAL_SEED, proposal.id.to_le_bytes().as_ref(), dao_config.key().as_ref()],
+        bump = proposal.bump,
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    #[account(
+        mut,
+        seeds = [MEMBER_SEED, voter.key().as_ref(), dao_config.key().as_ref()],
+        bump = voter_member.bump
+    )]
+    pub voter_member: Account<'info, Member>,
+
+    #[account(
+        mut,
+        seeds = [VOTE_SEED, voter_member.key().as_ref(), proposal.key().as_ref()],
+        bump,
+    )]
+    pub vote: Account<'info, Vote>,
+}
+
+impl<'info> ChangeVoteOnProposal<'info> {
+    pub fn change_vote_on_proposal(&mut self, vote: u8) -> Result<()> {
+        require!(
+            self.proposal.status == Status::Pending,
+            ProthesisError::ProposalNotPending
+        );
+
+        // Revealed votes are final
+        require!(
+            !self.dao_config.uses_commit_reveal(&self.proposal.kind),
+            ProthesisError::VotesMustBeCommitted
+        );
+
+        // Support for a conviction proposal can only be retracted
+        require!(
+            self.proposal.kind != ProposalKind::Conviction,
+            ProthesisError::ConvictionSupportOnly
+        );
+
+        let vote_type = VoteType::try_from(vote)?;
+
+        // Abstaining has no intensity to spend the quadratic vote's credits on
+        require!(
+            self.proposal.kind != ProposalKind::Quadratic || vote_type != VoteType::Abstain,
+            ProthesisError::InvalidVoteType
+        );
+
+        // A vote cast by a delegate can be taken over as is, overriding the delegation
+        require!(
+            vote_type != self.vote.vote_type || self.vote.cast_by.is_some(),
+            ProthesisError::VoteUnchanged
+        );
+
+        // The vote is the member's own from now on
+        if self.vote.cast_by.is_some() {
+            self.voter_member.close_delegated_vote();
+        }
+
+        self.proposal
+            .remove_vote(&self.vote.vote_type, self.vote.weight)?;
+        self.proposal.add_vote(&vote_type, self.vote.weight)?;
+        self.vote.vote_type = vote_type;
+        self.vote.cast_by = None;
+
+        Ok(())
+    }
+}
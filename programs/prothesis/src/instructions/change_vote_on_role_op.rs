@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{DAO_CONFIG_SEED, MEMBER_SEED, VOTE_SEED},
+    error::ProthesisError,
+    state::{DAOConfig, Member, RoleOp, RoleOpType, Status, Vote, VoteType},
+};
+
+#[derive(Accounts)]
+pub struct ChangeVoteOnRoleOp<'info> {
+    pub voter: Signer<'info>,
+
+    #[account(
+        seeds = [DAO_CONFIG_SEED, dao_config.id.to_le_bytes().as_ref()],
+        bump = dao_config.bump,
+    )]
+    pub dao_config: Account<'info, DAOConfig>,
+
+    #[account(
+        mut,
+        seeds = [role_op.seed.as_ref(), role_op.member.as_ref(), dao_config.key().as_ref(), role_op.id.to_le_bytes().as_ref()],
+        bump = role_op.bump,
+    )]
+    pub role_op: Account<'info, RoleOp>,
+
+    #[account(
+        seeds = [MEMBER_SEED, voter.key().as_ref(), dao_config.key().as_ref()],
+        bump = voter_member.bump
+    )]
+    pub voter_member: Account<'info, Member>,
+
+    #[account(
+        mut,
+        seeds = [VOTE_SEED, voter_member.key().as_ref(), role_op.key().as_ref()],
+        bump,
+    )]
+    pub vote: Account<'info, Vote>,
+}
+
+impl<'info> ChangeVoteOnRoleOp<'info> {
+    pub fn change_vote_on_role_op(&mut self, vote: u8) -> Result<()> {
+        require!(
+            self.role_op.status == Status::Pending,
+            ProthesisError::RoleOpNotPending
+        );
+
+        // Members who have since left the council cannot sway a council vote
+        match self.role_op.op_type {
+            RoleOpType::PromoteToCouncil | RoleOpType::DemoteFromCouncil => {
+                require!(
+                    self.voter_member.is_council,
+                    ProthesisError::NotCouncilMember,
+                );
+            }
+            RoleOpType::RemoveMember => {}
+        }
+
+        let vote_type = VoteType::try_from(vote)?;
+        require!(
+            vote_type != self.vote.vote_type,
+            ProthesisError::VoteUnchanged
+        );
+
+        self.role_op.remove_vote(&self.vote.vote_type)?;
+        self.role_op.add_vote(&vote_type)?;
+        self.vote.vote_type = vote_type;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{BALLOT_SEED, DAO_CONFIG_SEED, MEMBER_SEED},
+    state::{Ballot, DAOConfig},
+    utils::require_voting_ended,
+};
+
+#[derive(Accounts)]
+pub struct CloseBallot<'info> {
+    #[account(mut)]
+    pub voter: Signer<'info>,
+
+    #[account(
+        seeds = [DAO_CONFIG_SEED, dao_config.id.to_le_bytes().as_ref()],
+        bump = dao_config.bump,
+    )]
+    pub dao_config: Account<'info, DAOConfig>,
+
+    /// CHECK: Only used to derive the ballot PDA, so ballots can be closed after leaving the DAO
+    #[account(
+        seeds = [MEMBER_SEED, voter.key().as_ref(), dao_config.key().as_ref()],
+        bump,
+    )]
+    pub voter_member: UncheckedAccount<'info>,
+
+    /// CHECK: The proposal voted on. It is validated in the handler since it may already be closed.
+    pub proposal: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        close = voter,
+        seeds = [BALLOT_SEED, voter_member.key().as_ref(), proposal.key().as_ref()],
+        bump = ballot.bump,
+    )]
+    pub ballot: Account<'info, Ballot>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CloseBallot<'info> {
+    /// Closes the ballot once the proposal has been reviewed, refunding the rent to the voter
+    pub fn close_ballot(&mut self) -> Result<()> {
+        require_voting_ended(&self.proposal)
+    }
+}
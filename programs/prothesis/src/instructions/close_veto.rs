@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{DAO_CONFIG_SEED, MEMBER_SEED, VETO_SEED},
+    error::ProthesisError,
+    state::{DAOConfig, Proposal, Status, Veto},
+};
+
+#[derive(Accounts)]
+pub struct CloseVeto<'info> {
+    #[account(mut)]
+    pub vetoer: Signer<'info>,
+
+    #[account(
+        seeds = [DAO_CONFIG_SEED, dao_config.id.to_le_bytes().as_ref()],
+        bump = dao_config.bump,
+    )]
+    pub dao_config: Account<'info, DAOConfig>,
+
+    /// CHECK: Only used to derive the veto PDA, so vetoes can be closed after leaving the council
+    #[account(
+        seeds = [MEMBER_SEED, vetoer.key().as_ref(), dao_config.key().as_ref()],
+        bump,
+    )]
+    pub council_member: UncheckedAccount<'info>,
+
+    /// CHECK: The vetoed proposal. It is validated in the handler since it may already be closed.
+    pub proposal: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        close = vetoer,
+        seeds = [VETO_SEED, council_member.key().as_ref(), proposal.key().as_ref()],
+        bump = veto.bump,
+    )]
+    pub veto: Account<'info, Veto>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CloseVeto<'info> {
+    /// Closes the veto once it can no longer count, refunding the rent to the council member
+    pub fn close_veto(&mut self) -> Result<()> {
+        // Resolved proposals are closed, so there is nothing left to veto
+        if self.proposal.owner != &crate::ID || self.proposal.data_is_empty() {
+            return Ok(());
+        }
+
+        let proposal = Proposal::try_deserialize(&mut &self.proposal.try_borrow_data()?[..])?;
+        require!(
+            proposal.status != Status::Approved
+                || Clock::get()?.unix_timestamp
+                    >= self.dao_config.veto_deadline(proposal.approved_at),
+            ProthesisError::VotingNotEnded
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,109 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{DAO_CONFIG_SEED, MEMBER_SEED, VOTE_SEED},
+    error::ProthesisError,
+    state::{DAOConfig, Member, Vote},
+    utils::{close_account, require_voting_ended},
+};
+
+#[derive(Accounts)]
+pub struct CloseVote<'info> {
+    #[account(mut)]
+    pub voter: Signer<'info>,
+
+    #[account(
+        seeds = [DAO_CONFIG_SEED, dao_config.id.to_le_bytes().as_ref()],
+        bump = dao_config.bump,
+    )]
+    pub dao_config: Account<'info, DAOConfig>,
+
+    /// CHECK: Used to derive the vote PDAs, so votes can be closed after leaving the DAO, and to
+    /// stop counting the closed votes a delegate cast while the voter is still a member
+    #[account(
+        mut,
+        seeds = [MEMBER_SEED, voter.key().as_ref(), dao_config.key().as_ref()],
+        bump,
+    )]
+    pub voter_member: UncheckedAccount<'info>,
+
+    /// CHECK: The proposal or role op voted on. It is validated in the handler since it may already be closed.
+    pub target: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        close = rent_payer,
+        seeds = [VOTE_SEED, voter_member.key().as_ref(), target.key().as_ref()],
+        bump,
+    )]
+    pub vote: Account<'info, Vote>,
+
+    /// CHECK: The account that paid the vote's rent, the voter unless a relayer or delegate cast it
+    #[account(mut, address = vote.payer @ ProthesisError::InvalidRentPayer)]
+    pub rent_payer: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CloseVote<'info> {
+    /// Closes the vote, plus any additional votes passed in the remaining accounts as
+    /// `[target, vote, rent_payer]` triples, refunding the rent to whoever paid it
+    pub fn close_vote(&mut self, remaining_accounts: Vec<AccountInfo>) -> Result<()> {
+        require_voting_ended(&self.target)?;
+
+        let mut delegated_votes = u64::from(self.vote.cast_by.is_some());
+
+        let votes = remaining_accounts.chunks_exact(3);
+        require!(
+            votes.remainder().is_empty(),
+            ProthesisError::InvalidVoteAccount
+        );
+
+        for accounts in votes {
+            let (target, vote, rent_payer) = (&accounts[0], &accounts[1], &accounts[2]);
+
+            // Re-derive the vote PDA so that only the voter's own votes can be closed
+            let (expected_vote, _) = Pubkey::find_program_address(
+                &[
+                    VOTE_SEED,
+                    self.voter_member.key().as_ref(),
+                    target.key().as_ref(),
+                ],
+                &crate::ID,
+            );
+            require_keys_eq!(
+                vote.key(),
+                expected_vote,
+                ProthesisError::InvalidVoteAccount
+            );
+            require_keys_eq!(*vote.owner, crate::ID, ProthesisError::InvalidVoteAccount);
+            let vote_data = Vote::try_deserialize(&mut &vote.try_borrow_data()?[..])
+                .map_err(|_| ProthesisError::InvalidVoteAccount)?;
+
+            require_voting_ended(target)?;
+
+            if vote_data.cast_by.is_some() {
+                delegated_votes += 1;
+            }
+            require_keys_eq!(
+                rent_payer.key(),
+                vote_data.payer,
+                ProthesisError::InvalidRentPayer
+            );
+            close_account(vote, rent_payer)?;
+        }
+
+        if delegated_votes > 0
+            && self.voter_member.owner == &crate::ID
+            && !self.voter_member.data_is_empty()
+        {
+            let mut member =
+                Member::try_deserialize(&mut &self.voter_member.try_borrow_data()?[..])?;
+            // Saturates, like `Member::close_delegated_vote`
+            member.delegated_votes = member.delegated_votes.saturating_sub(delegated_votes);
+            member.try_serialize(&mut &mut self.voter_member.try_borrow_mut_data()?[..])?;
+        }
+
+        Ok(())
+    }
+}
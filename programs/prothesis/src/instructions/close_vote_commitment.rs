@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{DAO_CONFIG_SEED, MEMBER_SEED, VOTE_COMMITMENT_SEED},
+    error::ProthesisError,
+    state::{DAOConfig, Proposal, Status, VoteCommitment},
+};
+
+#[derive(Accounts)]
+pub struct CloseVoteCommitment<'info> {
+    #[account(mut)]
+    pub voter: Signer<'info>,
+
+    #[account(
+        seeds = [DAO_CONFIG_SEED, dao_config.id.to_le_bytes().as_ref()],
+        bump = dao_config.bump,
+    )]
+    pub dao_config: Account<'info, DAOConfig>,
+
+    /// CHECK: Only used to derive the commitment PDA, so commitments can be closed after leaving the DAO
+    #[account(
+        seeds = [MEMBER_SEED, voter.key().as_ref(), dao_config.key().as_ref()],
+        bump,
+    )]
+    pub voter_member: UncheckedAccount<'info>,
+
+    /// CHECK: The proposal voted on. It is validated in the handler since it may already be closed.
+    pub proposal: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        close = voter,
+        seeds = [VOTE_COMMITMENT_SEED, voter_member.key().as_ref(), proposal.key().as_ref()],
+        bump = commitment.bump,
+    )]
+    pub commitment: Account<'info, VoteCommitment>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CloseVoteCommitment<'info> {
+    /// Closes a commitment that was never revealed, refunding the rent to the voter
+    pub fn close_vote_commitment(&mut self) -> Result<()> {
+        // Resolved proposals are closed, so there is nothing left to reveal
+        if self.proposal.owner != &crate::ID || self.proposal.data_is_empty() {
+            return Ok(());
+        }
+
+        // Votes can no longer be revealed once the proposal is reviewed or cancelled
+        let proposal = Proposal::try_deserialize(&mut &self.proposal.try_borrow_data()?[..])?;
+        require!(
+            proposal.status != Status::Pending
+                || Clock::get()?.unix_timestamp
+                    >= self.dao_config.reveal_deadline(proposal.voting_opened_at),
+            ProthesisError::RevealPhaseNotEnded
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,106 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{
+        DAO_CONFIG_SEED, GOVERNANCE_DEPOSIT_SEED, MEMBER_SEED, PROPOSAL_SEED, VOTE_COMMITMENT_SEED,
+    },
+    error::ProthesisError,
+    state::{DAOConfig, GovernanceDeposit, Member, Proposal, Status, VoteCommitment},
+};
+
+#[derive(Accounts)]
+pub struct CommitVote<'info> {
+    #[account(mut)]
+    pub voter: Signer<'info>,
+
+    #[account(
+        seeds = [DAO_CONFIG_SEED, dao_config.id.to_le_bytes().as_ref()],
+        bump = dao_config.bump,
+    )]
+    pub dao_config: Account<'info, DAOConfig>,
+
+    #[account(
+        seeds = [PROPOSAL_SEED, proposal.id.to_le_bytes().as_ref(), dao_config.key().as_ref()],
+        bump = proposal.bump,
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    #[account(
+        seeds = [MEMBER_SEED, voter.key().as_ref(), dao_config.key().as_ref()],
+        bump = voter_member.bump
+    )]
+    pub voter_member: Account<'info, Member>,
+
+    #[account(
+        init,
+        payer = voter,
+        seeds = [VOTE_COMMITMENT_SEED, voter_member.key().as_ref(), proposal.key().as_ref()],
+        bump,
+        space = VoteCommitment::SPACE
+    )]
+    pub commitment: Account<'info, VoteCommitment>,
+
+    /// The voter's governance token deposit, required in token-weighted mode
+    #[account(
+        mut,
+        seeds = [GOVERNANCE_DEPOSIT_SEED, voter.key().as_ref(), dao_config.key().as_ref()],
+        bump = voter_deposit.bump,
+    )]
+    pub voter_deposit: Option<Account<'info, GovernanceDeposit>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CommitVote<'info> {
+    pub fn commit_vote(&mut self, hash: [u8; 32], bumps: &CommitVoteBumps) -> Result<()> {
+        require!(
+            self.proposal.status == Status::Pending,
+            ProthesisError::ProposalNotPending
+        );
+        require!(
+            self.dao_config.uses_commit_reveal(&self.proposal.kind),
+            ProthesisError::CommitRevealNotEnabled
+        );
+
+        // Only members counted in the electorate snapshot can vote
+        require!(
+            self.voter_member.joined_at <= self.proposal.voting_opened_at,
+            ProthesisError::VoterNotEligible
+        );
+
+        let now = Clock::get()?.unix_timestamp;
+        require!(
+            now < self
+                .dao_config
+                .voting_deadline(self.proposal.voting_opened_at),
+            ProthesisError::VotingPeriodEnded
+        );
+
+        let weight = if self.dao_config.is_token_weighted() {
+            let deposit = self
+                .voter_deposit
+                .as_mut()
+                .ok_or(ProthesisError::GovernanceDepositRequired)?;
+            require!(deposit.amount > 0, ProthesisError::NoVotingWeight);
+
+            // The tokens back the vote until it can no longer be revealed
+            deposit.lock_vote(
+                self.dao_config
+                    .reveal_deadline(self.proposal.voting_opened_at),
+            )?;
+
+            deposit.amount
+        } else {
+            1
+        };
+
+        self.commitment.set_inner(VoteCommitment {
+            hash,
+            weight,
+            committed_at: now,
+            bump: bumps.commitment,
+        });
+
+        Ok(())
+    }
+}
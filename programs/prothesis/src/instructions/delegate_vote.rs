@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{DAO_CONFIG_SEED, DELEGATION_SEED, MEMBER_SEED},
+    error::ProthesisError,
+    state::{DAOConfig, Delegation, Member},
+};
+
+#[derive(Accounts)]
+pub struct DelegateVote<'info> {
+    #[account(mut)]
+    pub delegator: Signer<'info>,
+
+    #[account(
+        seeds = [DAO_CONFIG_SEED, dao_config.id.to_le_bytes().as_ref()],
+        bump = dao_config.bump,
+    )]
+    pub dao_config: Account<'info, DAOConfig>,
+
+    #[account(
+        seeds = [MEMBER_SEED, delegator.key().as_ref(), dao_config.key().as_ref()],
+        bump = delegator_member.bump
+    )]
+    pub delegator_member: Account<'info, Member>,
+
+    #[account(
+        seeds = [MEMBER_SEED, delegate_member.owner.as_ref(), dao_config.key().as_ref()],
+        bump = delegate_member.bump,
+        constraint = delegate_member.key() != delegator_member.key() @ ProthesisError::SelfDelegation
+    )]
+    pub delegate_member: Account<'info, Member>,
+
+    #[account(
+        init,
+        payer = delegator,
+        seeds = [DELEGATION_SEED, delegator_member.key().as_ref(), dao_config.key().as_ref()],
+        bump,
+        space = Delegation::SPACE
+    )]
+    pub delegation: Account<'info, Delegation>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> DelegateVote<'info> {
+    pub fn delegate_vote(&mut self, bumps: &DelegateVoteBumps) -> Result<()> {
+        self.delegation.set_inner(Delegation {
+            delegator: self.delegator_member.key(),
+            delegate: self.delegate_member.key(),
+            delegated_at: Clock::get()?.unix_timestamp,
+            bump: bumps.delegation,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,108 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+use crate::{
+    constants::{DAO_CONFIG_SEED, GOVERNANCE_DEPOSIT_SEED, GOVERNANCE_VAULT_SEED, MEMBER_SEED},
+    error::ProthesisError,
+    state::{DAOConfig, GovernanceDeposit, Member},
+};
+
+#[derive(Accounts)]
+pub struct DepositGovernanceTokens<'info> {
+    #[account(mut)]
+    pub depositor: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [DAO_CONFIG_SEED, dao_config.id.to_le_bytes().as_ref()],
+        bump = dao_config.bump,
+    )]
+    pub dao_config: Account<'info, DAOConfig>,
+
+    #[account(
+        seeds = [MEMBER_SEED, depositor.key().as_ref(), dao_config.key().as_ref()],
+        bump = depositor_member.bump
+    )]
+    pub depositor_member: Account<'info, Member>,
+
+    #[account(
+        constraint = dao_config.governance_mint == Some(governance_mint.key()) @ ProthesisError::InvalidGovernanceMint
+    )]
+    pub governance_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        token::mint = governance_mint,
+        token::authority = depositor,
+        token::token_program = token_program,
+    )]
+    pub depositor_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = depositor,
+        seeds = [GOVERNANCE_VAULT_SEED, dao_config.key().as_ref()],
+        bump,
+        token::mint = governance_mint,
+        token::authority = dao_config,
+        token::token_program = token_program,
+    )]
+    pub governance_vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = depositor,
+        seeds = [GOVERNANCE_DEPOSIT_SEED, depositor.key().as_ref(), dao_config.key().as_ref()],
+        bump,
+        space = GovernanceDeposit::SPACE
+    )]
+    pub deposit: Account<'info, GovernanceDeposit>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> DepositGovernanceTokens<'info> {
+    pub fn deposit_governance_tokens(
+        &mut self,
+        amount: u64,
+        bumps: &DepositGovernanceTokensBumps,
+    ) -> Result<()> {
+        let cpi_accounts = TransferChecked {
+            from: self.depositor_token_account.to_account_info(),
+            mint: self.governance_mint.to_account_info(),
+            to: self.governance_vault.to_account_info(),
+            authority: self.depositor.to_account_info(),
+        };
+        let vault_balance = self.governance_vault.amount;
+        let cpi_ctx = CpiContext::new(self.token_program.to_account_info(), cpi_accounts);
+        transfer_checked(cpi_ctx, amount, self.governance_mint.decimals)?;
+
+        // Token-2022 transfer fees are withheld from the vault, so only the tokens it received count
+        self.governance_vault.reload()?;
+        let received = self
+            .governance_vault
+            .amount
+            .checked_sub(vault_balance)
+            .ok_or(ProthesisError::CountOutOfRange)?;
+
+        // The deposit may already exist from an earlier deposit, keeping its lock
+        self.deposit.owner = self.depositor.key();
+        self.deposit.bump = bumps.deposit;
+        self.deposit.amount = self
+            .deposit
+            .amount
+            .checked_add(received)
+            .ok_or(ProthesisError::CountOutOfRange)?;
+
+        self.dao_config.total_governance_tokens = self
+            .dao_config
+            .total_governance_tokens
+            .checked_add(received)
+            .ok_or(ProthesisError::CountOutOfRange)?;
+
+        Ok(())
+    }
+}
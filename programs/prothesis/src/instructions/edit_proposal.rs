@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{DAO_CONFIG_SEED, MEMBER_SEED, PROPOSAL_SEED},
+    error::ProthesisError,
+    state::{DAOConfig, Member, Proposal, ProposalContent, Status},
+};
+
+#[derive(Accounts)]
+#[instruction(content: ProposalContent)]
+pub struct EditProposal<'info> {
+    #[account(mut)]
+    pub author: Signer<'info>,
+
+    #[account(
+        seeds = [DAO_CONFIG_SEED, dao_config.id.to_le_bytes().as_ref()],
+        bump = dao_config.bump,
+    )]
+    pub dao_config: Account<'info, DAOConfig>,
+
+    #[account(
+        seeds = [MEMBER_SEED, author.key().as_ref(), dao_config.key().as_ref()],
+        bump = author_member.bump
+    )]
+    pub author_member: Account<'info, Member>,
+
+    #[account(
+        mut,
+        seeds = [PROPOSAL_SEED, proposal.id.to_le_bytes().as_ref(), dao_config.key().as_ref()],
+        bump = proposal.bump,
+        constraint = proposal.author == author_member.key() @ ProthesisError::NotProposalAuthor,
+        realloc = Proposal::space_for(&content),
+        realloc::payer = author,
+        realloc::zero = false,
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> EditProposal<'info> {
+    /// Updates the content and funding request of an unsponsored draft proposal, resizing it to the new content
+    pub fn edit_proposal(
+        &mut self,
+        content: ProposalContent,
+        treasury: Pubkey,
+        amount_required: u64,
+    ) -> Result<()> {
+        require!(
+            self.proposal.status == Status::Draft,
+            ProthesisError::NotDraft
+        );
+        // Sponsors backed the proposal as it was, so its content is frozen once sponsored
+        require!(
+            self.proposal.sponsors == 0,
+            ProthesisError::ProposalSponsored
+        );
+        content.validate()?;
+
+        // Choice proposals are funded through their winning option instead
+        require!(
+            !self.proposal.kind.has_options() || amount_required == 0,
+            ProthesisError::InvalidProposalOptions
+        );
+
+        self.proposal.content = content;
+        self.proposal.treasury = treasury;
+        self.proposal.amount_required = amount_required;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,155 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{
+        DAO_CONFIG_SEED, FUNDING_APPROVAL_SEED, MEMBER_SEED, PROPOSAL_SEED, TREASURY_SEED,
+    },
+    error::ProthesisError,
+    state::{DAOConfig, FundingApproval, Member, Proposal, Status},
+    utils::{require_funding_unlocked, transfer_from_treasury},
+};
+
+#[derive(Accounts)]
+pub struct ExecuteFunding<'info> {
+    // Anybody can execute the funding once enough council members have approved it
+    #[account(mut)]
+    pub executor: Signer<'info>,
+
+    #[account(
+        seeds = [DAO_CONFIG_SEED, dao_config.id.to_le_bytes().as_ref()],
+        bump = dao_config.bump,
+    )]
+    pub dao_config: Account<'info, DAOConfig>,
+
+    #[account(
+        mut,
+        seeds = [PROPOSAL_SEED, proposal.id.to_le_bytes().as_ref(), dao_config.key().as_ref()],
+        bump = proposal.bump,
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    #[account(
+        mut,
+        seeds = [TREASURY_SEED, dao_config.key().as_ref()],
+        bump = dao_config.treasury_bump
+    )]
+    pub dao_treasury: SystemAccount<'info>,
+
+    /// CHECK: This is validated with the treasury field in the proposal struct
+    #[account(mut)]
+    pub proposal_treasury: SystemAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ExecuteFunding<'info> {
+    /// Transfers the funding of an approved proposal. The funding approvals must be passed in the
+    /// remaining accounts as `[funding_approval, approver_member]` pairs, so that only approvers
+    /// who are still council members are counted.
+    pub fn execute_funding(&mut self, remaining_accounts: Vec<AccountInfo>) -> Result<()> {
+        match self.proposal.status {
+            Status::Approved => {}
+            Status::Executed => return Err(ProthesisError::AlreadyExecuted.into()),
+            _ => return Err(ProthesisError::ProposalNotApproved.into()),
+        }
+
+        require_funding_unlocked(&self.dao_config, &self.proposal)?;
+
+        require!(
+            self.count_council_approvals(remaining_accounts)?
+                >= self.dao_config.min_multisig_signers,
+            ProthesisError::InsufficientMultisigSigners
+        );
+
+        require!(
+            self.proposal_treasury.key() == self.proposal.treasury.key(),
+            ProthesisError::MismatchedTreasuryAccount
+        );
+
+        transfer_from_treasury(
+            &self.dao_config,
+            &self.dao_treasury,
+            self.proposal_treasury.to_account_info(),
+            &self.system_program,
+            self.proposal.amount_required,
+        )?;
+
+        self.proposal.status = Status::Executed;
+
+        Ok(())
+    }
+
+    pub fn count_council_approvals(&self, remaining_accounts: Vec<AccountInfo>) -> Result<u64> {
+        let pairs = remaining_accounts.chunks_exact(2);
+        require!(
+            pairs.remainder().is_empty(),
+            ProthesisError::InvalidFundingApproval
+        );
+
+        let dao_config_key = self.dao_config.key();
+        let proposal_key = self.proposal.key();
+        let mut counted: Vec<Pubkey> = Vec::new();
+
+        for pair in pairs {
+            let (funding_approval, approver_member) = (&pair[0], &pair[1]);
+
+            // The approver must still be a council member of the DAO
+            require_keys_eq!(
+                *approver_member.owner,
+                crate::ID,
+                ProthesisError::InvalidMemberAccount
+            );
+            let member = Member::try_deserialize(&mut &approver_member.try_borrow_data()?[..])
+                .map_err(|_| ProthesisError::InvalidMemberAccount)?;
+            let expected_member = Pubkey::create_program_address(
+                &[
+                    MEMBER_SEED,
+                    member.owner.as_ref(),
+                    dao_config_key.as_ref(),
+                    &[member.bump],
+                ],
+                &crate::ID,
+            )
+            .map_err(|_| ProthesisError::InvalidMemberAccount)?;
+            require_keys_eq!(
+                approver_member.key(),
+                expected_member,
+                ProthesisError::InvalidMemberAccount
+            );
+            require!(member.is_council, ProthesisError::NotCouncilMember);
+
+            // The approval must be the member's, for this proposal, and only counted once
+            require_keys_eq!(
+                *funding_approval.owner,
+                crate::ID,
+                ProthesisError::InvalidFundingApproval
+            );
+            let approval_data =
+                FundingApproval::try_deserialize(&mut &funding_approval.try_borrow_data()?[..])
+                    .map_err(|_| ProthesisError::InvalidFundingApproval)?;
+            let expected_approval = Pubkey::create_program_address(
+                &[
+                    FUNDING_APPROVAL_SEED,
+                    approver_member.key().as_ref(),
+                    proposal_key.as_ref(),
+                    &[approval_data.bump],
+                ],
+                &crate::ID,
+            )
+            .map_err(|_| ProthesisError::InvalidFundingApproval)?;
+            require_keys_eq!(
+                funding_approval.key(),
+                expected_approval,
+                ProthesisError::InvalidFundingApproval
+            );
+            require!(
+                !counted.contains(&funding_approval.key()),
+                ProthesisError::InvalidFundingApproval
+            );
+
+            counted.push(funding_approval.key());
+        }
+
+        Ok(counted.len() as u64)
+    }
+}
@@ -1,9 +1,12 @@
 use anchor_lang::prelude::*;
 
 use crate::{
-    constants::{DAO_CONFIG_SEED, MEMBER_SEED, TREASURY_SEED},
+    constants::{
+        DAO_CONFIG_SEED, DELEGATION_SEED, MEMBER_SEED, PROPOSAL_SEED, TREASURY_SEED, VOTE_SEED,
+    },
     error::ProthesisError,
-    state::{DAOConfig, Member},
+    state::{DAOConfig, Delegation, Member, Proposal, Status, Vote},
+    utils::close_account,
 };
 
 #[derive(Accounts)]
@@ -33,11 +36,29 @@ pub struct ExitDao<'info> {
     )]
     pub treasury: SystemAccount<'info>,
 
+    /// The exiting member's vote delegation, if any, so no more votes are cast on their behalf
+    #[account(
+        mut,
+        close = exiter,
+        seeds = [DELEGATION_SEED, exiting_member.key().as_ref(), dao_config.key().as_ref()],
+        bump = delegation.bump,
+    )]
+    pub delegation: Option<Account<'info, Delegation>>,
+
     pub system_program: Program<'info, System>,
 }
 
 impl<'info> ExitDao<'info> {
-    pub fn exit_dao(&mut self) -> Result<()> {
+    /// Removes the member from the DAO. Every vote their delegate cast for them must be passed in
+    /// the remaining accounts as `[proposal, vote, rent_payer]` triples, and is closed to the delegate
+    /// that paid its rent. Votes on pending proposals are taken out of the tallies first.
+    pub fn exit_dao(&mut self, remaining_accounts: Vec<AccountInfo>) -> Result<()> {
+        self.remove_delegated_votes(remaining_accounts)?;
+        require!(
+            self.exiting_member.delegated_votes == 0,
+            ProthesisError::DelegatedVotesOutstanding
+        );
+
         if self.exiting_member.is_council {
             self.dao_config.council_count = self
                 .dao_config
@@ -54,4 +75,85 @@ impl<'info> ExitDao<'info> {
 
         Ok(())
     }
+
+    pub fn remove_delegated_votes(&mut self, remaining_accounts: Vec<AccountInfo>) -> Result<()> {
+        let votes = remaining_accounts.chunks_exact(3);
+        require!(
+            votes.remainder().is_empty(),
+            ProthesisError::InvalidVoteAccount
+        );
+
+        let dao_config_key = self.dao_config.key();
+        let exiting_member_key = self.exiting_member.key();
+
+        for accounts in votes {
+            let (proposal_info, vote, rent_payer) = (&accounts[0], &accounts[1], &accounts[2]);
+
+            // Re-derive the vote PDA so that only the exiting member's votes can be removed
+            let (expected_vote, _) = Pubkey::find_program_address(
+                &[
+                    VOTE_SEED,
+                    exiting_member_key.as_ref(),
+                    proposal_info.key().as_ref(),
+                ],
+                &crate::ID,
+            );
+            require_keys_eq!(
+                vote.key(),
+                expected_vote,
+                ProthesisError::InvalidVoteAccount
+            );
+            require_keys_eq!(*vote.owner, crate::ID, ProthesisError::InvalidVoteAccount);
+            let vote_data = Vote::try_deserialize(&mut &vote.try_borrow_data()?[..])
+                .map_err(|_| ProthesisError::InvalidVoteAccount)?;
+
+            // Votes the member cast themselves are theirs to retract
+            require!(
+                vote_data.cast_by.is_some(),
+                ProthesisError::InvalidVoteAccount
+            );
+
+            // Resolved proposals are closed, and votes on other reviewed proposals no longer count
+            if proposal_info.owner == &crate::ID && !proposal_info.data_is_empty() {
+                let mut proposal =
+                    Proposal::try_deserialize(&mut &proposal_info.try_borrow_data()?[..])
+                        .map_err(|_| ProthesisError::InvalidVoteAccount)?;
+                let expected_proposal = Pubkey::create_program_address(
+                    &[
+                        PROPOSAL_SEED,
+                        proposal.id.to_le_bytes().as_ref(),
+                        dao_config_key.as_ref(),
+                        &[proposal.bump],
+                    ],
+                    &crate::ID,
+                )
+                .map_err(|_| ProthesisError::InvalidVoteAccount)?;
+                require_keys_eq!(
+                    proposal_info.key(),
+                    expected_proposal,
+                    ProthesisError::InvalidVoteAccount
+                );
+
+                if proposal.status == Status::Pending {
+                    proposal.remove_vote(&vote_data.vote_type, vote_data.weight)?;
+                    proposal.voters_count = proposal
+                        .voters_count
+                        .checked_sub(1)
+                        .ok_or(ProthesisError::CountOutOfRange)?;
+                    proposal.try_serialize(&mut &mut proposal_info.try_borrow_mut_data()?[..])?;
+                }
+            }
+
+            // The delegate paid the vote's rent
+            require_keys_eq!(
+                rent_payer.key(),
+                vote_data.payer,
+                ProthesisError::InvalidRentPayer
+            );
+            close_account(vote, rent_payer)?;
+            self.exiting_member.close_delegated_vote();
+        }
+
+        Ok(())
+    }
 }
@@ -1,10 +1,35 @@
 use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
 
 use crate::{
     constants::{DAO_CONFIG_SEED, MEMBER_SEED, TREASURY_SEED},
-    state::{DAOConfig, Member},
+    error::ProthesisError,
+    state::{DAOConfig, Member, RentDestination},
 };
 
+/// Governance parameters of a new DAO, see `DAOConfig` for their meaning
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct DAOParams {
+    pub consensus_pct: u16,
+    pub quorum_pct: u16,
+    pub consensus_lifetime: i64,
+    pub min_multisig_signers: u64,
+    pub proposal_rent_destination: RentDestination,
+    pub voice_credits_per_round: u64,
+    pub voice_credit_round_duration: i64,
+    pub conviction_half_life: i64,
+    pub conviction_min_pct: u16,
+    pub reveal_lifetime: i64,
+    pub veto_period: i64,
+    pub veto_pct: u16,
+    pub execution_delay: i64,
+    pub min_sponsors: u64,
+    pub proposal_deposit: u64,
+    pub forfeit_dismissed_deposits: bool,
+    pub max_open_proposals_per_member: u64,
+    pub proposal_cooldown: i64,
+}
+
 #[derive(Accounts)]
 #[instruction(id: u64)]
 pub struct InitializeDAO<'info> {
@@ -35,6 +60,9 @@ pub struct InitializeDAO<'info> {
     )]
     pub treasury: SystemAccount<'info>,
 
+    /// Governance token mint, proposal votes are weighted by deposited tokens if provided
+    pub governance_mint: Option<InterfaceAccount<'info, Mint>>,
+
     pub system_program: Program<'info, System>,
 }
 
@@ -42,28 +70,88 @@ impl<'info> InitializeDAO<'info> {
     pub fn initialize_dao(
         &mut self,
         id: u64,
-        consensus_pct: u16,
-        consensus_lifetime: i64,
-        min_multisig_signers: u64,
+        params: DAOParams,
         bumps: &InitializeDAOBumps,
     ) -> Result<()> {
+        require!(
+            params.consensus_pct <= 10_000
+                && params.quorum_pct <= 10_000
+                && params.conviction_min_pct <= 10_000
+                && params.veto_pct <= 10_000,
+            ProthesisError::InvalidPercentage
+        );
+        require!(
+            params.voice_credits_per_round == 0 || params.voice_credit_round_duration > 0,
+            ProthesisError::InvalidRoundDuration
+        );
+        require!(
+            params.conviction_half_life >= 0,
+            ProthesisError::InvalidHalfLife
+        );
+        require!(
+            params.reveal_lifetime >= 0,
+            ProthesisError::InvalidRevealLifetime
+        );
+        require!(params.veto_period >= 0, ProthesisError::InvalidVetoPeriod);
+        // A threshold of 0% would let a single council member veto any proposal
+        require!(params.veto_pct > 0, ProthesisError::InvalidVetoPct);
+        require!(
+            params.execution_delay >= 0,
+            ProthesisError::InvalidExecutionDelay
+        );
+        require!(
+            params.proposal_cooldown >= 0,
+            ProthesisError::InvalidProposalCooldown
+        );
+        // The escrow is a plain system account, so any deposit must keep it rent-exempt
+        require!(
+            params.proposal_deposit == 0
+                || params.proposal_deposit >= Rent::get()?.minimum_balance(0),
+            ProthesisError::InvalidProposalDeposit
+        );
+
         // Initialize DAO Config
         self.dao_config.set_inner(DAOConfig {
             id,
-            consensus_pct,
-            consensus_lifetime,
-            min_multisig_signers,
+            consensus_pct: params.consensus_pct,
+            quorum_pct: params.quorum_pct,
+            consensus_lifetime: params.consensus_lifetime,
+            min_multisig_signers: params.min_multisig_signers,
+            proposal_rent_destination: params.proposal_rent_destination,
+            governance_mint: self.governance_mint.as_ref().map(|mint| mint.key()),
+            total_governance_tokens: 0,
+            voice_credits_per_round: params.voice_credits_per_round,
+            voice_credit_round_duration: params.voice_credit_round_duration,
+            conviction_half_life: params.conviction_half_life,
+            conviction_min_pct: params.conviction_min_pct,
+            reveal_lifetime: params.reveal_lifetime,
+            veto_period: params.veto_period,
+            veto_pct: params.veto_pct,
+            execution_delay: params.execution_delay,
+            min_sponsors: params.min_sponsors,
+            proposal_deposit: params.proposal_deposit,
+            forfeit_dismissed_deposits: params.forfeit_dismissed_deposits,
+            max_open_proposals_per_member: params.max_open_proposals_per_member,
+            proposal_cooldown: params.proposal_cooldown,
             members_count: 1,
             council_count: 1,
+            proposal_count: 0,
+            role_op_count: 0,
             bump: bumps.dao_config,
             treasury_bump: bumps.treasury,
         });
 
         // Initialize creator as a council member
+        let now = Clock::get()?.unix_timestamp;
         self.member.set_inner(Member {
             owner: self.creator.key(),
             is_council: true,
-            joined_at: Clock::get()?.unix_timestamp,
+            joined_at: now,
+            council_since: now,
+            relay_nonce: 0,
+            open_proposals: 0,
+            last_proposal_at: 0,
+            delegated_votes: 0,
             bump: bumps.member,
         });
 
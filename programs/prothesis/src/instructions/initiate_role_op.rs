@@ -3,7 +3,6 @@ use anchor_lang::prelude::*;
 use crate::{
     constants::{DAO_CONFIG_SEED, MEMBER_SEED},
     error::ProthesisError,
-    role_op,
     state::{DAOConfig, Member, RoleOp, RoleOpType, Status},
 };
 
@@ -14,6 +13,7 @@ pub struct InitiateRoleOp<'info> {
     pub council_signer: Signer<'info>,
 
     #[account(
+        mut,
         seeds = [DAO_CONFIG_SEED, dao_config.id.to_le_bytes().as_ref()],
         bump = dao_config.bump
     )]
@@ -22,7 +22,7 @@ pub struct InitiateRoleOp<'info> {
     #[account(
         init,
         payer = council_signer,
-        seeds = [role_op_seed.as_ref(), nominated_member.key().as_ref(), dao_config.key().as_ref()],
+        seeds = [role_op_seed.as_ref(), nominated_member.key().as_ref(), dao_config.key().as_ref(), dao_config.role_op_count.to_le_bytes().as_ref()],
         bump,
         space = RoleOp::SPACE
     )]
@@ -64,17 +64,34 @@ impl<'info> InitiateRoleOp<'info> {
             )
         }
 
+        // Council roles are voted on by the council, and removals by every member
+        let electorate = match role_op_type {
+            RoleOpType::PromoteToCouncil | RoleOpType::DemoteFromCouncil => {
+                self.dao_config.council_count
+            }
+            RoleOpType::RemoveMember => self.dao_config.members_count,
+        };
+
         self.role_op.set_inner(RoleOp {
+            id: self.dao_config.role_op_count,
             seed: role_op_seed.to_vec(),
             op_type: role_op_type,
             member: self.nominated_member.key(),
             upvotes: 0,
             downvotes: 0,
+            abstentions: 0,
+            electorate,
             created_at: Clock::get()?.unix_timestamp,
             status: Status::Pending,
             bump: bumps.role_op,
         });
 
+        self.dao_config.role_op_count = self
+            .dao_config
+            .role_op_count
+            .checked_add(1)
+            .ok_or(ProthesisError::CountOutOfRange)?;
+
         Ok(())
     }
 }
@@ -1,25 +1,81 @@
 pub mod add_member;
+pub mod approve_funding;
+pub mod cancel_proposal;
+pub mod cast_delegated_votes;
+pub mod change_vote_on_proposal;
+pub mod change_vote_on_role_op;
+pub mod close_ballot;
+pub mod close_veto;
+pub mod close_vote;
+pub mod close_vote_commitment;
+pub mod commit_vote;
+pub mod delegate_vote;
+pub mod deposit_governance_tokens;
+pub mod edit_proposal;
+pub mod execute_funding;
 pub mod exit_dao;
 pub mod fund_dao;
 pub mod initialize_dao;
 pub mod initiate_role_op;
+pub mod open_voting;
+pub mod quadratic_vote;
+pub mod relay_vote;
 pub mod resolve_proposal;
 pub mod resolve_role_op;
+pub mod retract_vote_on_proposal;
+pub mod retract_vote_on_role_op;
+pub mod reveal_vote;
 pub mod review_proposal;
 pub mod review_role_op;
+pub mod revoke_delegation;
+pub mod revoke_funding_approval;
+pub mod sponsor_proposal;
 pub mod submit_proposal;
+pub mod update_conviction;
+pub mod veto_proposal;
+pub mod vote_on_options;
 pub mod vote_on_proposal;
 pub mod vote_on_role_op;
+pub mod withdraw_governance_tokens;
+pub mod withdraw_sponsorship;
 
 pub use add_member::*;
+pub use approve_funding::*;
+pub use cancel_proposal::*;
+pub use cast_delegated_votes::*;
+pub use change_vote_on_proposal::*;
+pub use change_vote_on_role_op::*;
+pub use close_ballot::*;
+pub use close_veto::*;
+pub use close_vote::*;
+pub use close_vote_commitment::*;
+pub use commit_vote::*;
+pub use delegate_vote::*;
+pub use deposit_governance_tokens::*;
+pub use edit_proposal::*;
+pub use execute_funding::*;
 pub use exit_dao::*;
 pub use fund_dao::*;
 pub use initialize_dao::*;
 pub use initiate_role_op::*;
+pub use open_voting::*;
+pub use quadratic_vote::*;
+pub use relay_vote::*;
 pub use resolve_proposal::*;
 pub use resolve_role_op::*;
+pub use retract_vote_on_proposal::*;
+pub use retract_vote_on_role_op::*;
+pub use reveal_vote::*;
 pub use review_proposal::*;
 pub use review_role_op::*;
+pub use revoke_delegation::*;
+pub use revoke_funding_approval::*;
+pub use sponsor_proposal::*;
 pub use submit_proposal::*;
+pub use update_conviction::*;
+pub use veto_proposal::*;
+pub use vote_on_options::*;
 pub use vote_on_proposal::*;
 pub use vote_on_role_op::*;
+pub use withdraw_governance_tokens::*;
+pub use withdraw_sponsorship::*;
@@ -0,0 +1,150 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{DAO_CONFIG_SEED, MEMBER_SEED, PROPOSAL_SEED, SPONSORSHIP_SEED},
+    error::ProthesisError,
+    state::{DAOConfig, Member, Proposal, ProposalKind, Sponsorship, Status},
+};
+
+#[derive(Accounts)]
+pub struct OpenVoting<'info> {
+    pub author: Signer<'info>,
+
+    #[account(
+        seeds = [DAO_CONFIG_SEED, dao_config.id.to_le_bytes().as_ref()],
+        bump = dao_config.bump,
+    )]
+    pub dao_config: Account<'info, DAOConfig>,
+
+    #[account(
+        seeds = [MEMBER_SEED, author.key().as_ref(), dao_config.key().as_ref()],
+        bump = author_member.bump
+    )]
+    pub author_member: Account<'info, Member>,
+
+    #[account(
+        mut,
+        seeds = [PROPOSAL_SEED, proposal.id.to_le_bytes().as_ref(), dao_config.key().as_ref()],
+        bump = proposal.bump,
+        constraint = proposal.author == author_member.key() @ ProthesisError::NotProposalAuthor
+    )]
+    pub proposal: Account<'info, Proposal>,
+}
+
+impl<'info> OpenVoting<'info> {
+    /// Opens a draft proposal with enough sponsors for voting. The electorate is snapshotted and
+    /// the voting period starts now, rather than when the draft was submitted.
+    ///
+    /// The sponsorships must be passed in the remaining accounts as `[sponsorship, sponsor_member]`
+    /// pairs, so that only sponsors who are still members of the DAO are counted.
+    pub fn open_voting(&mut self, remaining_accounts: Vec<AccountInfo>) -> Result<()> {
+        require!(
+            self.proposal.status == Status::Draft,
+            ProthesisError::NotDraft
+        );
+        require!(
+            self.count_sponsors(remaining_accounts)? >= self.dao_config.min_sponsors,
+            ProthesisError::NotEnoughSponsors
+        );
+
+        let now = Clock::get()?.unix_timestamp;
+        let (round, electorate) = match self.proposal.kind {
+            ProposalKind::Standard if self.dao_config.is_token_weighted() => {
+                (0, self.dao_config.total_governance_tokens)
+            }
+            ProposalKind::Standard => (0, self.dao_config.members_count),
+            ProposalKind::Quadratic => (
+                self.dao_config.voice_credit_round(now),
+                self.dao_config.members_count,
+            ),
+            // Support is staked per member, so it cannot be moved between members like tokens
+            ProposalKind::Conviction => (0, self.dao_config.members_count),
+            // Ballots are cast one per member, whatever the governance tokens they hold
+            ProposalKind::MultipleChoice | ProposalKind::RankedChoice => {
+                (0, self.dao_config.members_count)
+            }
+        };
+
+        self.proposal.round = round;
+        self.proposal.electorate = electorate;
+        self.proposal.council_size = self.dao_config.council_count;
+        self.proposal.conviction_updated_at = now;
+        self.proposal.voting_opened_at = now;
+        self.proposal.status = Status::Pending;
+
+        Ok(())
+    }
+
+    pub fn count_sponsors(&self, remaining_accounts: Vec<AccountInfo>) -> Result<u64> {
+        let pairs = remaining_accounts.chunks_exact(2);
+        require!(
+            pairs.remainder().is_empty(),
+            ProthesisError::InvalidSponsorship
+        );
+
+        let dao_config_key = self.dao_config.key();
+        let proposal_key = self.proposal.key();
+        let mut counted: Vec<Pubkey> = Vec::new();
+
+        for pair in pairs {
+            let (sponsorship, sponsor_member) = (&pair[0], &pair[1]);
+
+            // The sponsor must still be a member of the DAO
+            require_keys_eq!(
+                *sponsor_member.owner,
+                crate::ID,
+                ProthesisError::InvalidMemberAccount
+            );
+            let member = Member::try_deserialize(&mut &sponsor_member.try_borrow_data()?[..])
+                .map_err(|_| ProthesisError::InvalidMemberAccount)?;
+            let expected_member = Pubkey::create_program_address(
+                &[
+                    MEMBER_SEED,
+                    member.owner.as_ref(),
+                    dao_config_key.as_ref(),
+                    &[member.bump],
+                ],
+                &crate::ID,
+            )
+            .map_err(|_| ProthesisError::InvalidMemberAccount)?;
+            require_keys_eq!(
+                sponsor_member.key(),
+                expected_member,
+                ProthesisError::InvalidMemberAccount
+            );
+
+            // The sponsorship must be the member's, for this proposal, and only counted once
+            require_keys_eq!(
+                *sponsorship.owner,
+                crate::ID,
+                ProthesisError::InvalidSponsorship
+            );
+            let sponsorship_data =
+                Sponsorship::try_deserialize(&mut &sponsorship.try_borrow_data()?[..])
+                    .map_err(|_| ProthesisError::InvalidSponsorship)?;
+            let expected_sponsorship = Pubkey::create_program_address(
+                &[
+                    SPONSORSHIP_SEED,
+                    sponsor_member.key().as_ref(),
+                    proposal_key.as_ref(),
+                    &[sponsorship_data.bump],
+                ],
+                &crate::ID,
+            )
+            .map_err(|_| ProthesisError::InvalidSponsorship)?;
+            require_keys_eq!(
+                sponsorship.key(),
+                expected_sponsorship,
+                ProthesisError::InvalidSponsorship
+            );
+            require!(
+                !counted.contains(&sponsorship.key()),
+                ProthesisError::InvalidSponsorship
+            );
+
+            counted.push(sponsorship.key());
+        }
+
+        Ok(counted.len() as u64)
+    }
+}
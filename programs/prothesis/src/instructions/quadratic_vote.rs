@@ -0,0 +1,120 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{DAO_CONFIG_SEED, MEMBER_SEED, PROPOSAL_SEED, VOICE_CREDITS_SEED, VOTE_SEED},
+    error::ProthesisError,
+    state::{DAOConfig, Member, Proposal, ProposalKind, Status, VoiceCreditLedger, Vote, VoteType},
+};
+
+#[derive(Accounts)]
+pub struct QuadraticVote<'info> {
+    #[account(mut)]
+    pub voter: Signer<'info>,
+
+    #[account(
+        seeds = [DAO_CONFIG_SEED, dao_config.id.to_le_bytes().as_ref()],
+        bump = dao_config.bump,
+    )]
+    pub dao_config: Account<'info, DAOConfig>,
+
+    #[account(
+        mut,
+        seeds = [PROPOSAL_SEED, proposal.id.to_le_bytes().as_ref(), dao_config.key().as_ref()],
+        bump = proposal.bump,
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    #[account(
+        seeds = [MEMBER_SEED, voter.key().as_ref(), dao_config.key().as_ref()],
+        bump = voter_member.bump
+    )]
+    pub voter_member: Account<'info, Member>,
+
+    #[account(
+        init,
+        payer = voter,
+        seeds = [VOTE_SEED, voter_member.key().as_ref(), proposal.key().as_ref()],
+        bump,
+        space = Vote::SPACE
+    )]
+    pub vote: Account<'info, Vote>,
+
+    #[account(
+        init_if_needed,
+        payer = voter,
+        seeds = [VOICE_CREDITS_SEED, voter_member.key().as_ref(), dao_config.key().as_ref(), proposal.round.to_le_bytes().as_ref()],
+        bump,
+        space = VoiceCreditLedger::SPACE
+    )]
+    pub voice_credits: Account<'info, VoiceCreditLedger>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> QuadraticVote<'info> {
+    /// Casts `votes` votes of the given type, spending `votes²` voice credits of the proposal's round
+    pub fn quadratic_vote(
+        &mut self,
+        vote: u8,
+        votes: u64,
+        bumps: &QuadraticVoteBumps,
+    ) -> Result<()> {
+        require!(
+            self.proposal.status == Status::Pending,
+            ProthesisError::ProposalNotPending
+        );
+        require!(
+            self.proposal.kind == ProposalKind::Quadratic,
+            ProthesisError::WrongProposalKind
+        );
+
+        // Only members counted in the electorate snapshot can vote
+        require!(
+            self.voter_member.joined_at <= self.proposal.voting_opened_at,
+            ProthesisError::VoterNotEligible
+        );
+
+        // Abstaining has no intensity to spend credits on
+        let vote_type = VoteType::try_from(vote)?;
+        require!(
+            vote_type != VoteType::Abstain && votes > 0,
+            ProthesisError::InvalidVoteType
+        );
+
+        let cost = votes
+            .checked_mul(votes)
+            .ok_or(ProthesisError::InsufficientVoiceCredits)?;
+        let credits_spent = self
+            .voice_credits
+            .credits_spent
+            .checked_add(cost)
+            .ok_or(ProthesisError::InsufficientVoiceCredits)?;
+        require!(
+            credits_spent <= self.dao_config.voice_credits_per_round,
+            ProthesisError::InsufficientVoiceCredits
+        );
+
+        self.voice_credits.set_inner(VoiceCreditLedger {
+            member: self.voter_member.key(),
+            round: self.proposal.round,
+            credits_spent,
+            bump: bumps.voice_credits,
+        });
+
+        self.vote.set_inner(Vote {
+            vote_type,
+            weight: votes,
+            cast_by: None,
+            payer: self.voter.key(),
+        });
+        self.proposal
+            .add_vote(&self.vote.vote_type, self.vote.weight)?;
+        self.proposal.voters_count = self
+            .proposal
+            .voters_count
+            .checked_add(1)
+            .ok_or(ProthesisError::CountOutOfRange)?;
+
+        Ok(())
+    }
+}
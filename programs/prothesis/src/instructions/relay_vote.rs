@@ -0,0 +1,111 @@
+use anchor_lang::{prelude::*, solana_program::sysvar::instructions};
+
+use crate::{
+    constants::{DAO_CONFIG_SEED, GOVERNANCE_DEPOSIT_SEED, MEMBER_SEED, PROPOSAL_SEED, VOTE_SEED},
+    error::ProthesisError,
+    state::{DAOConfig, GovernanceDeposit, Member, Proposal, Status, Vote, VoteType},
+    utils::require_ed25519_signature,
+};
+
+#[derive(Accounts)]
+pub struct RelayVote<'info> {
+    /// Anybody submitting the signed vote, paying the fees and the vote rent
+    #[account(mut)]
+    pub relayer: Signer<'info>,
+
+    #[account(
+        seeds = [DAO_CONFIG_SEED, dao_config.id.to_le_bytes().as_ref()],
+        bump = dao_config.bump,
+    )]
+    pub dao_config: Account<'info, DAOConfig>,
+
+    #[account(
+        mut,
+        seeds = [PROPOSAL_SEED, proposal.id.to_le_bytes().as_ref(), dao_config.key().as_ref()],
+        bump = proposal.bump,
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    #[account(
+        mut,
+        seeds = [MEMBER_SEED, voter_member.owner.as_ref(), dao_config.key().as_ref()],
+        bump = voter_member.bump
+    )]
+    pub voter_member: Account<'info, Member>,
+
+    #[account(
+        init,
+        payer = relayer,
+        seeds = [VOTE_SEED, voter_member.key().as_ref(), proposal.key().as_ref()],
+        bump,
+        space = Vote::SPACE
+    )]
+    pub vote: Account<'info, Vote>,
+
+    /// The voter's governance token deposit, required in token-weighted mode
+    #[account(
+        mut,
+        seeds = [GOVERNANCE_DEPOSIT_SEED, voter_member.owner.as_ref(), dao_config.key().as_ref()],
+        bump = voter_deposit.bump,
+    )]
+    pub voter_deposit: Option<Account<'info, GovernanceDeposit>>,
+
+    /// CHECK: The instructions sysvar, used to inspect the ed25519 signature verification
+    #[account(address = instructions::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> RelayVote<'info> {
+    /// Casts a vote signed off-chain by the voter. The transaction must verify the voter's
+    /// signature of `Vote::relay_message` with an ed25519 program instruction placed right
+    /// before this one.
+    pub fn relay_vote(&mut self, vote: u8, nonce: u64) -> Result<()> {
+        require!(
+            self.proposal.status == Status::Pending,
+            ProthesisError::ProposalNotPending
+        );
+
+        let message =
+            Vote::relay_message(&self.dao_config.key(), &self.proposal.key(), vote, nonce);
+        require_ed25519_signature(
+            &self.instructions_sysvar.to_account_info(),
+            &self.voter_member.owner,
+            &message,
+        )?;
+
+        // Each signed message can only be relayed once
+        require!(
+            nonce == self.voter_member.relay_nonce,
+            ProthesisError::InvalidRelayNonce
+        );
+        self.voter_member.relay_nonce = self
+            .voter_member
+            .relay_nonce
+            .checked_add(1)
+            .ok_or(ProthesisError::CountOutOfRange)?;
+
+        // Only members counted in the electorate snapshot can vote
+        require!(
+            self.voter_member.joined_at <= self.proposal.voting_opened_at,
+            ProthesisError::VoterNotEligible
+        );
+
+        let vote_type = VoteType::try_from(vote)?;
+        let weight = self.proposal.cast_vote(
+            &self.dao_config,
+            self.voter_deposit.as_deref_mut(),
+            &vote_type,
+        )?;
+
+        self.vote.set_inner(Vote {
+            vote_type,
+            weight,
+            cast_by: None,
+            payer: self.relayer.key(),
+        });
+
+        Ok(())
+    }
+}
@@ -4,9 +4,14 @@ use anchor_lang::{
 };
 
 use crate::{
-    constants::{DAO_CONFIG_SEED, MEMBER_SEED, PROPOSAL_SEED, TREASURY_SEED},
+    constants::{
+        DAO_CONFIG_SEED, MEMBER_SEED, PROPOSAL_DEPOSIT_SEED, PROPOSAL_RECEIPT_SEED, PROPOSAL_SEED,
+        TREASURY_SEED,
+    },
     error::ProthesisError,
-    state::{DAOConfig, Member, Proposal, Status},
+    events::MultisigSignersVerified,
+    state::{DAOConfig, Member, Proposal, ProposalReceipt, RentDestination, Status},
+    utils::{require_funding_unlocked, transfer_from_treasury},
 };
 
 #[derive(Accounts)]
@@ -23,11 +28,25 @@ pub struct ResolveProposal<'info> {
 
     #[account(
         mut,
-        seeds = [PROPOSAL_SEED, proposal.title.as_bytes().as_ref(), dao_config.key().as_ref()],
+        close = rent_destination,
+        seeds = [PROPOSAL_SEED, proposal.id.to_le_bytes().as_ref(), dao_config.key().as_ref()],
         bump = proposal.bump,
     )]
     pub proposal: Account<'info, Proposal>,
 
+    /// CHECK: The author's member account, which may already be closed if the author exited the DAO
+    #[account(mut, address = proposal.author @ ProthesisError::NotProposalAuthor)]
+    pub author_member: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = resolver,
+        seeds = [PROPOSAL_RECEIPT_SEED, proposal.key().as_ref()],
+        bump,
+        space = ProposalReceipt::SPACE
+    )]
+    pub proposal_receipt: Account<'info, ProposalReceipt>,
+
     #[account(
         seeds = [DAO_CONFIG_SEED, dao_config.id.to_le_bytes().as_ref()],
         bump = dao_config.bump,
@@ -45,98 +64,250 @@ pub struct ResolveProposal<'info> {
     #[account(mut)]
     pub proposal_treasury: SystemAccount<'info>,
 
+    /// CHECK: This is validated against the rent destination configured for the DAO
+    #[account(mut)]
+    pub rent_destination: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [PROPOSAL_DEPOSIT_SEED, proposal.key().as_ref()],
+        bump,
+    )]
+    pub proposal_deposit: SystemAccount<'info>,
+
+    /// CHECK: This is validated against the outcome of the proposal
+    #[account(mut)]
+    pub deposit_recipient: UncheckedAccount<'info>,
+
     pub system_program: Program<'info, System>,
 }
 
 impl<'info> ResolveProposal<'info> {
-    pub fn resolve_proposal(&mut self, remaining_accounts: Vec<AccountInfo>) -> Result<()> {
+    pub fn resolve_proposal(
+        &mut self,
+        expected_content_hash: Option<[u8; 32]>,
+        remaining_accounts: Vec<AccountInfo>,
+        bumps: &ResolveProposalBumps,
+    ) -> Result<()> {
+        // Lets the resolver make sure the proposal content is the one they reviewed
+        if let Some(expected_content_hash) = expected_content_hash {
+            require!(
+                self.proposal.content.hash() == expected_content_hash,
+                ProthesisError::ContentHashMismatch
+            );
+        }
+
         match self.proposal.status {
             Status::Approved => {
+                // The council can still veto the proposal, and members exit the DAO, until it is unlocked
+                require_funding_unlocked(&self.dao_config, &self.proposal)?;
+
                 self.check_signers(remaining_accounts)?;
                 self.transfer_funds()?;
+                self.proposal.status = Status::Executed;
             }
-            Status::Dismissed | Status::Expired => {} // Do nothing, just close the account
-            Status::Pending => return Err(ProthesisError::CannotResolveBeforeReview.into()),
+            // Funded through the approvals, or not funded at all: just close the account
+            Status::Executed
+            | Status::Dismissed
+            | Status::Expired
+            | Status::QuorumNotReached
+            | Status::Vetoed
+            | Status::Cancelled => {}
+            Status::Draft | Status::Pending => {
+                return Err(ProthesisError::CannotResolveBeforeReview.into())
+            }
+        };
+
+        self.check_rent_destination()?;
+        self.release_deposit(bumps)?;
+        self.close_author_proposal()?;
+        self.record_receipt(bumps)
+    }
+
+    pub fn check_rent_destination(&self) -> Result<()> {
+        let expected_destination = match self.dao_config.proposal_rent_destination {
+            RentDestination::Author => self.proposal.author_wallet,
+            RentDestination::Treasury => self.dao_treasury.key(),
         };
 
+        require_keys_eq!(
+            self.rent_destination.key(),
+            expected_destination,
+            ProthesisError::InvalidRentDestination
+        );
+
+        Ok(())
+    }
+
+    /// Returns the proposal deposit to the author, unless the DAO forfeits the deposit of dismissed proposals.
+    /// Proposals cancelled after voting opened forfeit it as well, so authors cannot dodge a dismissal.
+    pub fn release_deposit(&self, bumps: &ResolveProposalBumps) -> Result<()> {
+        let forfeited = self.dao_config.forfeit_dismissed_deposits
+            && match self.proposal.status {
+                Status::Dismissed => true,
+                Status::Cancelled => self.proposal.voting_opened_at != 0,
+                _ => false,
+            };
+        let expected_recipient = if forfeited {
+            self.dao_treasury.key()
+        } else {
+            self.proposal.author_wallet
+        };
+
+        require_keys_eq!(
+            self.deposit_recipient.key(),
+            expected_recipient,
+            ProthesisError::InvalidDepositRecipient
+        );
+
+        let amount = self.proposal_deposit.lamports();
+        if amount == 0 {
+            return Ok(());
+        }
+
+        let proposal_key = self.proposal.key();
+        let seeds: &[&[&[u8]]] = &[&[
+            PROPOSAL_DEPOSIT_SEED,
+            proposal_key.as_ref(),
+            &[bumps.proposal_deposit],
+        ]];
+
+        let cpi_ctx = CpiContext::new_with_signer(
+            self.system_program.to_account_info(),
+            Transfer {
+                from: self.proposal_deposit.to_account_info(),
+                to: self.deposit_recipient.to_account_info(),
+            },
+            seeds,
+        );
+
+        transfer(cpi_ctx, amount)
+    }
+
+    /// Stops counting the proposal as open for its author. Cancelled proposals stopped counting already.
+    pub fn close_author_proposal(&self) -> Result<()> {
+        if self.proposal.status == Status::Cancelled
+            || self.author_member.owner != &crate::ID
+            || self.author_member.data_is_empty()
+        {
+            return Ok(());
+        }
+
+        let mut author_member =
+            Member::try_deserialize(&mut &self.author_member.try_borrow_data()?[..])?;
+        author_member.close_proposal();
+        author_member.try_serialize(&mut &mut self.author_member.try_borrow_mut_data()?[..])
+    }
+
+    pub fn record_receipt(&mut self, bumps: &ResolveProposalBumps) -> Result<()> {
+        self.proposal_receipt.set_inner(ProposalReceipt {
+            proposal: self.proposal.key(),
+            author: self.proposal.author,
+            content_hash: self.proposal.content.hash(),
+            treasury: self.proposal.treasury,
+            amount_required: self.proposal.amount_required,
+            upvotes: self.proposal.upvotes,
+            downvotes: self.proposal.downvotes,
+            abstentions: self.proposal.abstentions,
+            winning_option: self.proposal.winning_option,
+            status: self.proposal.status.clone(),
+            created_at: self.proposal.created_at,
+            resolved_at: Clock::get()?.unix_timestamp,
+            bump: bumps.proposal_receipt,
+        });
+
         Ok(())
     }
 
     pub fn check_signers(&self, remaining_accounts: Vec<AccountInfo>) -> Result<()> {
-        // If the resolver member itself is already a council member, that's enough
-        let mut found_council_member = self.resolver_member.is_council;
-        let mut signers_count: u64 = 1; // resolver_member counts as one signer
+        let dao_config = self.dao_config.key();
 
-        // Collect all signer public keys from the remaining accounts (the actual keypair signers)
-        let signer_pubkeys: Vec<Pubkey> = remaining_accounts
-            .iter()
-            .filter(|acct_info| acct_info.is_signer)
-            .map(|acct_info| *acct_info.key)
-            .collect();
+        // The resolver member is already verified by the account constraints and counts as one signer
+        let mut found_council_member = self.resolver_member.is_council;
+        let mut counted_members: Vec<Pubkey> = vec![self.resolver_member.key()];
 
-        // Iterate through all remaining accounts to find Member accounts
         for acct_info in remaining_accounts.iter() {
-            // Try to deserialize as Member
-            if let Ok(data) = acct_info.try_borrow_data() {
-                if let Ok(member) = Member::try_deserialize(&mut &data[..]) {
-                    // Check if this member's owner is one of the signer keys
-                    if signer_pubkeys.contains(&member.owner) {
-                        signers_count = signers_count
-                            .checked_add(1)
-                            .ok_or(ProthesisError::CountOutOfRange)?;
-
-                        // Check if this member is council
-                        if member.is_council {
-                            found_council_member = true;
-                        }
-
-                        // Break early if conditions met
-                        if found_council_member
-                            && signers_count >= self.dao_config.min_multisig_signers
-                        {
-                            break;
-                        }
-                    }
-                }
+            // Signers are the wallets backing the member accounts, they are not counted on their own
+            if acct_info.is_signer {
+                continue;
+            }
+
+            // Everything else must be a member account owned by this program
+            require_keys_eq!(
+                *acct_info.owner,
+                crate::ID,
+                ProthesisError::InvalidMemberAccount
+            );
+
+            let member = {
+                let data = acct_info.try_borrow_data()?;
+                Member::try_deserialize(&mut &data[..])
+                    .map_err(|_| ProthesisError::InvalidMemberAccount)?
+            };
+
+            // Re-derive the member PDA so that accounts of other DAOs are rejected
+            let expected_key = Pubkey::create_program_address(
+                &[
+                    MEMBER_SEED,
+                    member.owner.as_ref(),
+                    dao_config.as_ref(),
+                    &[member.bump],
+                ],
+                &crate::ID,
+            )
+            .map_err(|_| ProthesisError::InvalidMemberAccount)?;
+            require_keys_eq!(
+                expected_key,
+                acct_info.key(),
+                ProthesisError::InvalidMemberAccount
+            );
+
+            // The wallet that owns the member account must have signed the transaction
+            require!(
+                remaining_accounts
+                    .iter()
+                    .any(|signer| signer.is_signer && signer.key() == member.owner),
+                ProthesisError::MemberMustSign
+            );
+
+            // Each member can only be counted once, including the resolver
+            require!(
+                !counted_members.contains(&acct_info.key()),
+                ProthesisError::DuplicateSigner
+            );
+            counted_members.push(acct_info.key());
+
+            if member.is_council {
+                found_council_member = true;
             }
         }
 
         require!(found_council_member, ProthesisError::NoCouncilMemberSigned);
         require!(
-            signers_count >= self.dao_config.min_multisig_signers,
+            counted_members.len() as u64 >= self.dao_config.min_multisig_signers,
             ProthesisError::InsufficientMultisigSigners
         );
 
+        emit!(MultisigSignersVerified {
+            proposal: self.proposal.key(),
+            signers: counted_members,
+        });
+
         Ok(())
     }
-    pub fn transfer_funds(&mut self) -> Result<()> {
-        require!(
-            self.dao_treasury.to_account_info().lamports() > self.proposal.amount_required,
-            ProthesisError::InsufficientTreasuryBalance
-        );
 
+    pub fn transfer_funds(&mut self) -> Result<()> {
         require!(
             self.proposal_treasury.key() == self.proposal.treasury.key(),
             ProthesisError::MismatchedTreasuryAccount
         );
 
-        let cpi_program = self.system_program.to_account_info();
-
-        let cpi_accounts = Transfer {
-            from: self.dao_treasury.to_account_info(),
-            to: self.proposal_treasury.to_account_info(),
-        };
-
-        let dao_config = self.dao_config.key();
-
-        let seeds: &[&[&[u8]]] = &[&[
-            TREASURY_SEED,
-            &dao_config.as_ref(),
-            &[self.dao_config.treasury_bump],
-        ]];
-
-        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, seeds);
-
-        transfer(cpi_ctx, self.proposal.amount_required)
+        transfer_from_treasury(
+            &self.dao_config,
+            &self.dao_treasury,
+            self.proposal_treasury.to_account_info(),
+            &self.system_program,
+            self.proposal.amount_required,
+        )
     }
 }
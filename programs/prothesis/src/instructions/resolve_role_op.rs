@@ -1,9 +1,9 @@
 use anchor_lang::prelude::*;
 
 use crate::{
-    constants::{DAO_CONFIG_SEED, MEMBER_SEED, TREASURY_SEED},
+    constants::{DAO_CONFIG_SEED, MEMBER_SEED, ROLE_OP_RECEIPT_SEED, TREASURY_SEED},
     error::ProthesisError,
-    state::{DAOConfig, Member, RoleOp, RoleOpType, Status},
+    state::{DAOConfig, Member, RoleOp, RoleOpReceipt, RoleOpType, Status},
 };
 
 #[derive(Accounts)]
@@ -21,11 +21,20 @@ pub struct ResolveRoleOp<'info> {
     #[account(
         mut,
         close = treasury, // Rent goes to the treasury as a small contribution to the DAO
-        seeds = [role_op.seed.as_ref(), role_op.member.as_ref(), dao_config.key().as_ref()],
+        seeds = [role_op.seed.as_ref(), role_op.member.as_ref(), dao_config.key().as_ref(), role_op.id.to_le_bytes().as_ref()],
         bump = role_op.bump
     )]
     pub role_op: Account<'info, RoleOp>,
 
+    #[account(
+        init,
+        payer = resolver,
+        seeds = [ROLE_OP_RECEIPT_SEED, role_op.key().as_ref()],
+        bump,
+        space = RoleOpReceipt::SPACE
+    )]
+    pub role_op_receipt: Account<'info, RoleOpReceipt>,
+
     #[account(
         mut,
         seeds = [MEMBER_SEED, nominated_member.owner.key().as_ref(), dao_config.key().as_ref()],
@@ -50,7 +59,7 @@ pub struct ResolveRoleOp<'info> {
 }
 
 impl<'info> ResolveRoleOp<'info> {
-    pub fn resolve_role_op(&mut self) -> Result<()> {
+    pub fn resolve_role_op(&mut self, bumps: &ResolveRoleOpBumps) -> Result<()> {
         match self.role_op.status {
             Status::Approved => {
                 match self.role_op.op_type {
@@ -61,6 +70,7 @@ impl<'info> ResolveRoleOp<'info> {
                         );
 
                         self.nominated_member.is_council = true;
+                        self.nominated_member.council_since = Clock::get()?.unix_timestamp;
                         self.dao_config.council_count = self
                             .dao_config
                             .council_count
@@ -108,11 +118,36 @@ impl<'info> ResolveRoleOp<'info> {
                 }
             }
 
-            Status::Dismissed | Status::Expired => {} // Do nothing, just close the account
+            Status::Dismissed
+            | Status::Expired
+            | Status::QuorumNotReached
+            | Status::Vetoed
+            | Status::Cancelled => {} // Do nothing, just close the account
+
+            Status::Draft | Status::Pending => {
+                return Err(ProthesisError::CannotResolveBeforeReview.into())
+            }
 
-            Status::Pending => return Err(ProthesisError::CannotResolveBeforeReview.into()),
+            Status::Executed => return Err(ProthesisError::AlreadyExecuted.into()),
         };
 
+        self.record_receipt(bumps)
+    }
+
+    pub fn record_receipt(&mut self, bumps: &ResolveRoleOpBumps) -> Result<()> {
+        self.role_op_receipt.set_inner(RoleOpReceipt {
+            role_op: self.role_op.key(),
+            op_type: self.role_op.op_type.clone(),
+            member: self.role_op.member,
+            upvotes: self.role_op.upvotes,
+            downvotes: self.role_op.downvotes,
+            abstentions: self.role_op.abstentions,
+            status: self.role_op.status.clone(),
+            created_at: self.role_op.created_at,
+            resolved_at: Clock::get()?.unix_timestamp,
+            bump: bumps.role_op_receipt,
+        });
+
         Ok(())
     }
 }
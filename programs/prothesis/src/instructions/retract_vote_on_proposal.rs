@@ -0,0 +1,137 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{
+        DAO_CONFIG_SEED, GOVERNANCE_DEPOSIT_SEED, MEMBER_SEED, PROPOSAL_SEED, VOICE_CREDITS_SEED,
+        VOTE_SEED,
+    },
+    error::ProthesisError,
+    state::{
+        DAOConfig, GovernanceDeposit, Member, Proposal, ProposalKind, Status, VoiceCreditLedger,
+        Vote,
+    },
+};
+
+#[derive(Accounts)]
+pub struct RetractVoteOnProposal<'info> {
+    #[account(mut)]
+    pub voter: Signer<'info>,
+
+    #[account(
+        seeds = [DAO_CONFIG_SEED, dao_config.id.to_le_bytes().as_ref()],
+        bump = dao_config.bump,
+    )]
+    pub dao_config: Account<'info, DAOConfig>,
+
+    #[account(
+        mut,
+        seeds = [PROPOSAL_SEED, proposal.id.to_le_bytes().as_ref(), dao_config.key().as_ref()],
+        bump = proposal.bump,
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    #[account(
+        mut,
+        seeds = [MEMBER_SEED, voter.key().as_ref(), dao_config.key().as_ref()],
+        bump = voter_member.bump
+    )]
+    pub voter_member: Account<'info, Member>,
+
+    #[account(
+        mut,
+        close = rent_payer,
+        seeds = [VOTE_SEED, voter_member.key().as_ref(), proposal.key().as_ref()],
+        bump,
+    )]
+    pub vote: Account<'info, Vote>,
+
+    /// CHECK: The account that paid the vote's rent, the voter unless a relayer or delegate cast it
+    #[account(mut, address = vote.payer @ ProthesisError::InvalidRentPayer)]
+    pub rent_payer: UncheckedAccount<'info>,
+
+    /// The voter's voice credit ledger of the proposal's round, required to refund quadratic votes
+    #[account(
+        mut,
+        seeds = [VOICE_CREDITS_SEED, voter_member.key().as_ref(), dao_config.key().as_ref(), proposal.round.to_le_bytes().as_ref()],
+        bump = voice_credits.bump,
+    )]
+    pub voice_credits: Option<Account<'info, VoiceCreditLedger>>,
+
+    /// The voter's governance token deposit, required in token-weighted mode to release its lock
+    #[account(
+        mut,
+        seeds = [GOVERNANCE_DEPOSIT_SEED, voter.key().as_ref(), dao_config.key().as_ref()],
+        bump = voter_deposit.bump,
+    )]
+    pub voter_deposit: Option<Account<'info, GovernanceDeposit>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> RetractVoteOnProposal<'info> {
+    /// Retracts a vote on a pending proposal. Votes on cancelled proposals can be retracted too,
+    /// refunding their voice credits and releasing the governance tokens backing them.
+    pub fn retract_vote_on_proposal(&mut self) -> Result<()> {
+        require!(
+            matches!(self.proposal.status, Status::Pending | Status::Cancelled),
+            ProthesisError::ProposalNotPending
+        );
+
+        // Revealed votes are final
+        require!(
+            !self.dao_config.uses_commit_reveal(&self.proposal.kind),
+            ProthesisError::VotesMustBeCommitted
+        );
+
+        match self.proposal.kind {
+            ProposalKind::Standard if self.dao_config.is_token_weighted() => {
+                self.voter_deposit
+                    .as_mut()
+                    .ok_or(ProthesisError::GovernanceDepositRequired)?
+                    .release_vote();
+            }
+            ProposalKind::Standard => {}
+            // Ballots of choice proposals are final
+            ProposalKind::MultipleChoice | ProposalKind::RankedChoice => {
+                return Err(ProthesisError::WrongProposalKind.into())
+            }
+            ProposalKind::Quadratic => {
+                // Refund the voice credits spent on the vote
+                let voice_credits = self
+                    .voice_credits
+                    .as_mut()
+                    .ok_or(ProthesisError::VoiceCreditLedgerRequired)?;
+                let cost = self
+                    .vote
+                    .weight
+                    .checked_mul(self.vote.weight)
+                    .ok_or(ProthesisError::CountOutOfRange)?;
+                voice_credits.credits_spent = voice_credits
+                    .credits_spent
+                    .checked_sub(cost)
+                    .ok_or(ProthesisError::CountOutOfRange)?;
+            }
+            ProposalKind::Conviction => {
+                // Accrue the conviction of the support staked so far before unstaking
+                self.proposal.accrue_conviction(
+                    Clock::get()?.unix_timestamp,
+                    self.dao_config.conviction_half_life,
+                )?;
+            }
+        }
+
+        if self.vote.cast_by.is_some() {
+            self.voter_member.close_delegated_vote();
+        }
+
+        self.proposal
+            .remove_vote(&self.vote.vote_type, self.vote.weight)?;
+        self.proposal.voters_count = self
+            .proposal
+            .voters_count
+            .checked_sub(1)
+            .ok_or(ProthesisError::CountOutOfRange)?;
+
+        Ok(())
+    }
+}
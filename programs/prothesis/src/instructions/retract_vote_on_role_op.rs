@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{DAO_CONFIG_SEED, MEMBER_SEED, VOTE_SEED},
+    error::ProthesisError,
+    state::{DAOConfig, Member, RoleOp, Status, Vote},
+};
+
+#[derive(Accounts)]
+pub struct RetractVoteOnRoleOp<'info> {
+    #[account(mut)]
+    pub voter: Signer<'info>,
+
+    #[account(
+        seeds = [DAO_CONFIG_SEED, dao_config.id.to_le_bytes().as_ref()],
+        bump = dao_config.bump,
+    )]
+    pub dao_config: Account<'info, DAOConfig>,
+
+    #[account(
+        mut,
+        seeds = [role_op.seed.as_ref(), role_op.member.as_ref(), dao_config.key().as_ref(), role_op.id.to_le_bytes().as_ref()],
+        bump = role_op.bump,
+    )]
+    pub role_op: Account<'info, RoleOp>,
+
+    #[account(
+        seeds = [MEMBER_SEED, voter.key().as_ref(), dao_config.key().as_ref()],
+        bump = voter_member.bump
+    )]
+    pub voter_member: Account<'info, Member>,
+
+    #[account(
+        mut,
+        close = voter,
+        seeds = [VOTE_SEED, voter_member.key().as_ref(), role_op.key().as_ref()],
+        bump,
+    )]
+    pub vote: Account<'info, Vote>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> RetractVoteOnRoleOp<'info> {
+    pub fn retract_vote_on_role_op(&mut self) -> Result<()> {
+        require!(
+            self.role_op.status == Status::Pending,
+            ProthesisError::RoleOpNotPending
+        );
+
+        self.role_op.remove_vote(&self.vote.vote_type)
+    }
+}
@@ -0,0 +1,95 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{DAO_CONFIG_SEED, MEMBER_SEED, PROPOSAL_SEED, VOTE_COMMITMENT_SEED, VOTE_SEED},
+    error::ProthesisError,
+    state::{DAOConfig, Member, Proposal, Status, Vote, VoteCommitment, VoteType},
+};
+
+#[derive(Accounts)]
+pub struct RevealVote<'info> {
+    #[account(mut)]
+    pub voter: Signer<'info>,
+
+    #[account(
+        seeds = [DAO_CONFIG_SEED, dao_config.id.to_le_bytes().as_ref()],
+        bump = dao_config.bump,
+    )]
+    pub dao_config: Account<'info, DAOConfig>,
+
+    #[account(
+        mut,
+        seeds = [PROPOSAL_SEED, proposal.id.to_le_bytes().as_ref(), dao_config.key().as_ref()],
+        bump = proposal.bump,
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    #[account(
+        seeds = [MEMBER_SEED, voter.key().as_ref(), dao_config.key().as_ref()],
+        bump = voter_member.bump
+    )]
+    pub voter_member: Account<'info, Member>,
+
+    #[account(
+        mut,
+        close = voter,
+        seeds = [VOTE_COMMITMENT_SEED, voter_member.key().as_ref(), proposal.key().as_ref()],
+        bump = commitment.bump,
+    )]
+    pub commitment: Account<'info, VoteCommitment>,
+
+    #[account(
+        init,
+        payer = voter,
+        seeds = [VOTE_SEED, voter_member.key().as_ref(), proposal.key().as_ref()],
+        bump,
+        space = Vote::SPACE
+    )]
+    pub vote: Account<'info, Vote>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> RevealVote<'info> {
+    pub fn reveal_vote(&mut self, vote: u8, salt: [u8; 32]) -> Result<()> {
+        require!(
+            self.proposal.status == Status::Pending,
+            ProthesisError::ProposalNotPending
+        );
+
+        let now = Clock::get()?.unix_timestamp;
+        require!(
+            now >= self
+                .dao_config
+                .voting_deadline(self.proposal.voting_opened_at)
+                && now
+                    < self
+                        .dao_config
+                        .reveal_deadline(self.proposal.voting_opened_at),
+            ProthesisError::NotInRevealPhase
+        );
+
+        let hash =
+            VoteCommitment::hash(vote, &salt, &self.voter_member.key(), &self.proposal.key());
+        require!(
+            hash == self.commitment.hash,
+            ProthesisError::CommitmentMismatch
+        );
+
+        self.vote.set_inner(Vote {
+            vote_type: VoteType::try_from(vote)?,
+            weight: self.commitment.weight,
+            cast_by: None,
+            payer: self.voter.key(),
+        });
+        self.proposal
+            .add_vote(&self.vote.vote_type, self.vote.weight)?;
+        self.proposal.voters_count = self
+            .proposal
+            .voters_count
+            .checked_add(1)
+            .ok_or(ProthesisError::CountOutOfRange)?;
+
+        Ok(())
+    }
+}
@@ -1,9 +1,9 @@
 use anchor_lang::prelude::*;
 
 use crate::{
-    constants::{DAO_CONFIG_SEED, MEMBER_SEED, PROPOSAL_SEED},
+    constants::{BALLOT_SEED, DAO_CONFIG_SEED, MEMBER_SEED, PROPOSAL_SEED},
     error::ProthesisError,
-    state::{DAOConfig, Member, Proposal, Status},
+    state::{Ballot, DAOConfig, Member, Proposal, ProposalKind, RunoffRound, Status},
 };
 
 #[derive(Accounts)]
@@ -20,7 +20,7 @@ pub struct ReviewProposal<'info> {
 
     #[account(
         mut,
-        seeds = [PROPOSAL_SEED, proposal.title.as_bytes().as_ref(), dao_config.key().as_ref()],
+        seeds = [PROPOSAL_SEED, proposal.id.to_le_bytes().as_ref(), dao_config.key().as_ref()],
         bump = proposal.bump,
     )]
     pub proposal: Account<'info, Proposal>,
@@ -35,32 +35,193 @@ pub struct ReviewProposal<'info> {
 }
 
 impl<'info> ReviewProposal<'info> {
-    pub fn review_proposal(&mut self) -> Result<()> {
+    /// Reviews the proposal. Ranked-choice proposals are counted from their ballots, which are
+    /// passed in the remaining accounts and can be spread over several reviews.
+    pub fn review_proposal(&mut self, remaining_accounts: Vec<AccountInfo>) -> Result<()> {
+        require!(
+            self.proposal.status != Status::Draft,
+            ProthesisError::VotingNotOpen
+        );
+
         // Require proposal to be unreviewed
         require!(
             self.proposal.status == Status::Pending,
             ProthesisError::AlreadyReviewed
         );
 
-        let vote_threshold =
-            (self.dao_config.consensus_pct as u64 * self.dao_config.members_count) / 10_000;
+        // Conviction proposals have no voting period, they are funded once enough conviction accrues
+        require!(
+            self.proposal.kind != ProposalKind::Conviction,
+            ProthesisError::WrongProposalKind
+        );
 
-        // Check if upvotes have crossed the threshold
-        if self.proposal.upvotes >= vote_threshold {
-            self.proposal.status = Status::Approved
+        if self.proposal.kind.has_options() {
+            return self.review_choices(remaining_accounts);
         }
 
-        // Check if downvotes have crossed the threshold
-        if self.proposal.downvotes >= vote_threshold {
-            self.proposal.status = Status::Dismissed
+        // Committed votes are only tallied once the reveal phase is over
+        let commit_reveal = self.dao_config.uses_commit_reveal(&self.proposal.kind);
+        let now = Clock::get()?.unix_timestamp;
+        if commit_reveal {
+            require!(
+                now >= self
+                    .dao_config
+                    .reveal_deadline(self.proposal.voting_opened_at),
+                ProthesisError::RevealPhaseNotEnded
+            );
         }
 
-        // Check if proposal has passed the expiry time
-        let time_elasped = Clock::get()?.unix_timestamp - self.proposal.created_at;
-        if time_elasped >= self.dao_config.consensus_lifetime {
-            self.proposal.status = Status::Expired
+        // Turnout, abstentions included, is checked against the quorum, independently of the approval ratio.
+        // Quadratic votes are weighted by the credits spent, so their turnout is the number of voters instead.
+        let votes_cast = self
+            .proposal
+            .upvotes
+            .checked_add(self.proposal.downvotes)
+            .ok_or(ProthesisError::CountOutOfRange)?;
+        let turnout = match self.proposal.kind {
+            ProposalKind::Standard => votes_cast
+                .checked_add(self.proposal.abstentions)
+                .ok_or(ProthesisError::CountOutOfRange)?,
+            _ => self.proposal.voters_count,
+        };
+        let quorum_reached = turnout >= self.dao_config.quorum(self.proposal.electorate);
+
+        // Revealed votes are final, so the outcome of a commit-reveal proposal is decided once. Until
+        // the voting period ends, an outcome is only decided if the members who have not voted yet
+        // could no longer overturn it.
+        let voting_ended = commit_reveal
+            || now
+                >= self
+                    .dao_config
+                    .voting_deadline(self.proposal.voting_opened_at);
+        let outstanding_votes = if voting_ended {
+            0
+        } else {
+            self.proposal.outstanding_votes(&self.dao_config)
+        };
+        let deciding_votes = votes_cast
+            .checked_add(outstanding_votes)
+            .ok_or(ProthesisError::CountOutOfRange)?;
+
+        if quorum_reached {
+            // Check if upvotes have crossed the threshold
+            if self
+                .dao_config
+                .reaches_consensus(self.proposal.upvotes, deciding_votes)
+            {
+                self.proposal.status = Status::Approved;
+                self.proposal.approved_at = now;
+            }
+            // Check if downvotes have crossed the threshold
+            else if self
+                .dao_config
+                .reaches_consensus(self.proposal.downvotes, deciding_votes)
+            {
+                self.proposal.status = Status::Dismissed
+            }
+        }
+
+        // Proposals without a consensus once voting has ended expire
+        if voting_ended && self.proposal.status == Status::Pending {
+            self.proposal.status = if quorum_reached {
+                Status::Expired
+            } else {
+                Status::QuorumNotReached
+            }
         }
 
         Ok(())
     }
+
+    /// Decides the winning option once the voting period has ended. The proposal is approved
+    /// with the funding of the winning option, or expires if no option wins.
+    fn review_choices(&mut self, remaining_accounts: Vec<AccountInfo>) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        require!(
+            now >= self
+                .dao_config
+                .voting_deadline(self.proposal.voting_opened_at),
+            ProthesisError::VotingPeriodNotEnded
+        );
+
+        if self.proposal.voters_count < self.dao_config.quorum(self.proposal.electorate) {
+            self.proposal.status = Status::QuorumNotReached;
+            return Ok(());
+        }
+
+        let winner = match self.proposal.kind {
+            ProposalKind::RankedChoice => match self.count_ranked_choice(remaining_accounts)? {
+                RunoffRound::Winner(winner) => Some(winner),
+                RunoffRound::NoWinner => None,
+                // The count goes on with the ballots passed to the next review
+                _ => return Ok(()),
+            },
+            _ => self.proposal.plurality_winner(&self.dao_config),
+        };
+
+        match winner {
+            Some(winner) => {
+                let option = self.proposal.options[winner as usize].clone();
+                self.proposal.treasury = option.treasury;
+                self.proposal.amount_required = option.amount;
+                self.proposal.winning_option = Some(winner);
+                self.proposal.status = Status::Approved;
+                self.proposal.approved_at = now;
+            }
+            None => self.proposal.status = Status::Expired,
+        }
+
+        Ok(())
+    }
+
+    /// Runs the instant-runoff count as far as the ballots passed allow. Each round needs every
+    /// ballot once, so a count can span several reviews, each passing as many ballots as fit.
+    fn count_ranked_choice(&mut self, remaining_accounts: Vec<AccountInfo>) -> Result<RunoffRound> {
+        let proposal_key = self.proposal.key();
+        let mut ballots = remaining_accounts.iter();
+
+        loop {
+            match self.proposal.finish_runoff_round(&self.dao_config) {
+                RunoffRound::Counting => {}
+                RunoffRound::Eliminated => continue,
+                outcome => return Ok(outcome),
+            }
+
+            let Some(acct_info) = ballots.next() else {
+                return Ok(RunoffRound::Counting);
+            };
+
+            require_keys_eq!(*acct_info.owner, crate::ID, ProthesisError::InvalidBallots);
+            require!(acct_info.is_writable, ProthesisError::InvalidBallots);
+            let mut ballot = Ballot::try_deserialize(&mut &acct_info.try_borrow_data()?[..])
+                .map_err(|_| ProthesisError::InvalidBallots)?;
+
+            // Re-derive the ballot PDA so that ballots of other proposals are rejected
+            let expected_key = Pubkey::create_program_address(
+                &[
+                    BALLOT_SEED,
+                    ballot.voter.as_ref(),
+                    proposal_key.as_ref(),
+                    &[ballot.bump],
+                ],
+                &crate::ID,
+            )
+            .map_err(|_| ProthesisError::InvalidBallots)?;
+            require_keys_eq!(
+                expected_key,
+                acct_info.key(),
+                ProthesisError::InvalidBallots
+            );
+
+            // Each ballot can only be counted once per round
+            require!(
+                ballot.counted_round < self.proposal.runoff_round,
+                ProthesisError::InvalidBallots
+            );
+            self.proposal.count_runoff_ballot(&ballot.choices)?;
+
+            ballot.counted_round = self.proposal.runoff_round;
+            ballot.try_serialize(&mut &mut acct_info.try_borrow_mut_data()?[..])?;
+        }
+    }
 }
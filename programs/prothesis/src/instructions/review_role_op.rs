@@ -19,7 +19,7 @@ pub struct ReviewRoleOp<'info> {
 
     #[account(
         mut,
-        seeds = [role_op.seed.as_ref(), role_op.member.as_ref(), dao_config.key().as_ref()],
+        seeds = [role_op.seed.as_ref(), role_op.member.as_ref(), dao_config.key().as_ref(), role_op.id.to_le_bytes().as_ref()],
         bump = role_op.bump
     )]
     pub role_op: Account<'info, RoleOp>,
@@ -52,23 +52,54 @@ impl<'info> ReviewRoleOp<'info> {
             RoleOpType::RemoveMember => {} // Do nothing, anybody can review a removal
         }
 
-        let vote_threshold =
-            (self.dao_config.consensus_pct as u64 * self.dao_config.council_count) / 10_000;
+        // Turnout, abstentions included, is checked against the quorum, independently of the approval ratio
+        let votes_cast = self
+            .role_op
+            .upvotes
+            .checked_add(self.role_op.downvotes)
+            .ok_or(ProthesisError::CountOutOfRange)?;
+        let turnout = votes_cast
+            .checked_add(self.role_op.abstentions)
+            .ok_or(ProthesisError::CountOutOfRange)?;
+        let quorum_reached = turnout >= self.dao_config.quorum(self.role_op.electorate);
 
-        // Check if upvotes have crossed the threshold
-        if self.role_op.upvotes >= vote_threshold.max(1) {
-            self.role_op.status = Status::Approved
-        }
+        // Until the role operation expires, an outcome is only decided if the members who have not
+        // voted yet could no longer overturn it
+        let now = Clock::get()?.unix_timestamp;
+        let voting_ended = now >= self.dao_config.voting_deadline(self.role_op.created_at);
+        let outstanding_votes = if voting_ended {
+            0
+        } else {
+            self.role_op.electorate.saturating_sub(turnout)
+        };
+        let deciding_votes = votes_cast
+            .checked_add(outstanding_votes)
+            .ok_or(ProthesisError::CountOutOfRange)?;
 
-        // Check if downvotes have crossed the threshold
-        if self.role_op.downvotes >= vote_threshold.max(1) {
-            self.role_op.status = Status::Dismissed
+        if quorum_reached {
+            // Check if upvotes have crossed the threshold
+            if self
+                .dao_config
+                .reaches_consensus(self.role_op.upvotes, deciding_votes)
+            {
+                self.role_op.status = Status::Approved
+            }
+            // Check if downvotes have crossed the threshold
+            else if self
+                .dao_config
+                .reaches_consensus(self.role_op.downvotes, deciding_votes)
+            {
+                self.role_op.status = Status::Dismissed
+            }
         }
 
-        // Check if role operation has passed the expiry time
-        let time_elasped = Clock::get()?.unix_timestamp - self.role_op.created_at;
-        if time_elasped >= self.dao_config.consensus_lifetime {
-            self.role_op.status = Status::Expired
+        // Role operations without a consensus once voting has ended expire
+        if voting_ended && self.role_op.status == Status::Pending {
+            self.role_op.status = if quorum_reached {
+                Status::Expired
+            } else {
+                Status::QuorumNotReached
+            }
         }
 
         Ok(())
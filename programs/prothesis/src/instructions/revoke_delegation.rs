@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{DAO_CONFIG_SEED, DELEGATION_SEED, MEMBER_SEED},
+    state::{DAOConfig, Delegation},
+};
+
+#[derive(Accounts)]
+pub struct RevokeDelegation<'info> {
+    #[account(mut)]
+    pub delegator: Signer<'info>,
+
+    #[account(
+        seeds = [DAO_CONFIG_SEED, dao_config.id.to_le_bytes().as_ref()],
+        bump = dao_config.bump,
+    )]
+    pub dao_config: Account<'info, DAOConfig>,
+
+    /// CHECK: Only used to derive the delegation PDA, so it can be revoked after leaving the DAO
+    #[account(
+        seeds = [MEMBER_SEED, delegator.key().as_ref(), dao_config.key().as_ref()],
+        bump,
+    )]
+    pub delegator_member: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        close = delegator,
+        seeds = [DELEGATION_SEED, delegator_member.key().as_ref(), dao_config.key().as_ref()],
+        bump = delegation.bump,
+    )]
+    pub delegation: Account<'info, Delegation>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> RevokeDelegation<'info> {
+    pub fn revoke_delegation(&mut self) -> Result<()> {
+        // Votes already cast by the delegate are kept, the delegator can still change or retract them
+        Ok(())
+    }
+}
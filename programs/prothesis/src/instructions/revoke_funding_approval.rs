@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{DAO_CONFIG_SEED, FUNDING_APPROVAL_SEED, MEMBER_SEED},
+    error::ProthesisError,
+    state::{DAOConfig, FundingApproval, Proposal, Status},
+};
+
+#[derive(Accounts)]
+pub struct RevokeFundingApproval<'info> {
+    #[account(mut)]
+    pub approver: Signer<'info>,
+
+    #[account(
+        seeds = [DAO_CONFIG_SEED, dao_config.id.to_le_bytes().as_ref()],
+        bump = dao_config.bump,
+    )]
+    pub dao_config: Account<'info, DAOConfig>,
+
+    /// CHECK: Only used to derive the approval PDA, so the rent can be reclaimed after leaving the DAO
+    #[account(
+        seeds = [MEMBER_SEED, approver.key().as_ref(), dao_config.key().as_ref()],
+        bump,
+    )]
+    pub approver_member: UncheckedAccount<'info>,
+
+    /// CHECK: The approval PDA is derived from this key. It is only deserialized if still open,
+    /// since the proposal is closed once it is resolved.
+    #[account(mut)]
+    pub proposal: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        close = approver,
+        seeds = [FUNDING_APPROVAL_SEED, approver_member.key().as_ref(), proposal.key().as_ref()],
+        bump = funding_approval.bump,
+    )]
+    pub funding_approval: Account<'info, FundingApproval>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> RevokeFundingApproval<'info> {
+    pub fn revoke_funding_approval(&mut self) -> Result<()> {
+        let proposal_info = self.proposal.to_account_info();
+
+        // The proposal has been resolved and closed, only the rent is left to reclaim
+        if proposal_info.owner != &crate::ID || proposal_info.data_is_empty() {
+            return Ok(());
+        }
+
+        let mut proposal = Proposal::try_deserialize(&mut &proposal_info.try_borrow_data()?[..])?;
+
+        // Once the proposal is executed, closing the approval only reclaims its rent
+        if proposal.status == Status::Approved {
+            proposal.funding_approvals = proposal
+                .funding_approvals
+                .checked_sub(1)
+                .ok_or(ProthesisError::CountOutOfRange)?;
+
+            let mut data = proposal_info.try_borrow_mut_data()?;
+            proposal.try_serialize(&mut &mut data[..])?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{DAO_CONFIG_SEED, MEMBER_SEED, PROPOSAL_SEED, SPONSORSHIP_SEED},
+    error::ProthesisError,
+    state::{DAOConfig, Member, Proposal, Sponsorship, Status},
+};
+
+#[derive(Accounts)]
+pub struct SponsorProposal<'info> {
+    #[account(mut)]
+    pub sponsor: Signer<'info>,
+
+    #[account(
+        seeds = [DAO_CONFIG_SEED, dao_config.id.to_le_bytes().as_ref()],
+        bump = dao_config.bump,
+    )]
+    pub dao_config: Account<'info, DAOConfig>,
+
+    #[account(
+        seeds = [MEMBER_SEED, sponsor.key().as_ref(), dao_config.key().as_ref()],
+        bump = sponsor_member.bump
+    )]
+    pub sponsor_member: Account<'info, Member>,
+
+    #[account(
+        mut,
+        seeds = [PROPOSAL_SEED, proposal.id.to_le_bytes().as_ref(), dao_config.key().as_ref()],
+        bump = proposal.bump,
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    #[account(
+        init,
+        payer = sponsor,
+        seeds = [SPONSORSHIP_SEED, sponsor_member.key().as_ref(), proposal.key().as_ref()],
+        bump,
+        space = Sponsorship::SPACE
+    )]
+    pub sponsorship: Account<'info, Sponsorship>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SponsorProposal<'info> {
+    /// Sponsors a draft proposal, counting towards the sponsors it needs before voting can open
+    pub fn sponsor_proposal(&mut self, bumps: &SponsorProposalBumps) -> Result<()> {
+        require!(
+            self.proposal.status == Status::Draft,
+            ProthesisError::NotDraft
+        );
+        require_keys_neq!(
+            self.proposal.author,
+            self.sponsor_member.key(),
+            ProthesisError::SelfSponsorship
+        );
+
+        self.sponsorship.set_inner(Sponsorship {
+            sponsor: self.sponsor_member.key(),
+            proposal: self.proposal.key(),
+            sponsored_at: Clock::get()?.unix_timestamp,
+            bump: bumps.sponsorship,
+        });
+
+        self.proposal.sponsors = self
+            .proposal
+            .sponsors
+            .checked_add(1)
+            .ok_or(ProthesisError::CountOutOfRange)?;
+
+        Ok(())
+    }
+}
@@ -1,24 +1,40 @@
-use anchor_lang::prelude::*;
+use anchor_lang::{
+    prelude::*,
+    system_program::{transfer, Transfer},
+};
 
 use crate::{
-    constants::{DAO_CONFIG_SEED, MEMBER_SEED, PROPOSAL_SEED},
+    constants::{
+        DAO_CONFIG_SEED, MAX_OPTION_LABEL_LENGTH, MAX_PROPOSAL_OPTIONS, MAX_TITLE_LENGTH,
+        MEMBER_SEED, PROPOSAL_DEPOSIT_SEED, PROPOSAL_SEED,
+    },
     error::ProthesisError,
-    state::{DAOConfig, Member, Proposal, Status},
+    state::{DAOConfig, Member, Proposal, ProposalContent, ProposalKind, ProposalOption, Status},
 };
 
+/// An option of a new multiple-choice or ranked-choice proposal, see `ProposalOption` for their meaning
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ProposalOptionParams {
+    pub label: String,
+    pub treasury: Pubkey,
+    pub amount: u64,
+}
+
 #[derive(Accounts)]
-#[instruction(title: String)]
+#[instruction(title: String, content: ProposalContent)]
 pub struct SubmitProposal<'info> {
     #[account(mut)]
     pub author: Signer<'info>,
 
     #[account(
+        mut,
         seeds = [DAO_CONFIG_SEED, dao_config.id.to_le_bytes().as_ref()],
         bump = dao_config.bump,
     )]
     pub dao_config: Account<'info, DAOConfig>,
 
     #[account(
+        mut,
         seeds = [MEMBER_SEED, author.key().as_ref(), dao_config.key().as_ref()],
         bump = author_member.bump
     )]
@@ -27,41 +43,154 @@ pub struct SubmitProposal<'info> {
     #[account(
         init,
         payer = author,
-        seeds = [PROPOSAL_SEED, title.as_bytes().as_ref(), dao_config.key().as_ref()],
+        seeds = [PROPOSAL_SEED, dao_config.proposal_count.to_le_bytes().as_ref(), dao_config.key().as_ref()],
         bump,
-        space = Proposal::SPACE
+        space = Proposal::space_for(&content)
     )]
     pub proposal: Account<'info, Proposal>,
 
+    /// Escrow holding the proposal deposit until the proposal is resolved
+    #[account(
+        mut,
+        seeds = [PROPOSAL_DEPOSIT_SEED, proposal.key().as_ref()],
+        bump,
+    )]
+    pub proposal_deposit: SystemAccount<'info>,
+
     pub system_program: Program<'info, System>,
 }
 
 impl<'info> SubmitProposal<'info> {
+    #[allow(clippy::too_many_arguments)]
     pub fn submit_proposal(
         &mut self,
         title: String,
-        content: String,
+        content: ProposalContent,
         treasury: Pubkey,
         amount_required: u64,
+        kind: ProposalKind,
+        options: Vec<ProposalOptionParams>,
         bumps: &SubmitProposalBumps,
     ) -> Result<()> {
-        require!(title.len() <= 64, ProthesisError::TitleTooLong);
-        require!(content.len() <= 2048, ProthesisError::ContentTooLong);
+        require!(
+            title.len() <= MAX_TITLE_LENGTH,
+            ProthesisError::TitleTooLong
+        );
+        content.validate()?;
+
+        let now = Clock::get()?.unix_timestamp;
+        require!(
+            self.dao_config.max_open_proposals_per_member == 0
+                || self.author_member.open_proposals
+                    < self.dao_config.max_open_proposals_per_member,
+            ProthesisError::TooManyOpenProposals
+        );
+        require!(
+            self.author_member.last_proposal_at == 0
+                || now
+                    >= self
+                        .author_member
+                        .last_proposal_at
+                        .saturating_add(self.dao_config.proposal_cooldown),
+            ProthesisError::ProposalCooldownActive
+        );
+
+        // Choice proposals are funded through their winning option instead
+        if kind.has_options() {
+            require!(
+                (2..=MAX_PROPOSAL_OPTIONS).contains(&options.len()) && amount_required == 0,
+                ProthesisError::InvalidProposalOptions
+            );
+        } else {
+            require!(options.is_empty(), ProthesisError::InvalidProposalOptions);
+        }
+        let options = options
+            .into_iter()
+            .map(|option| {
+                require!(
+                    option.label.len() <= MAX_OPTION_LABEL_LENGTH,
+                    ProthesisError::OptionLabelTooLong
+                );
+                Ok(ProposalOption {
+                    label: option.label,
+                    treasury: option.treasury,
+                    amount: option.amount,
+                    votes: 0,
+                    eliminated: false,
+                    runoff_votes: 0,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        match kind {
+            ProposalKind::Quadratic => require!(
+                self.dao_config.voice_credits_per_round > 0,
+                ProthesisError::QuadraticVotingDisabled
+            ),
+            ProposalKind::Conviction => require!(
+                self.dao_config.conviction_half_life > 0,
+                ProthesisError::ConvictionVotingDisabled
+            ),
+            _ => {}
+        }
 
         self.proposal.set_inner(Proposal {
+            id: self.dao_config.proposal_count,
             author: self.author_member.key(),
+            author_wallet: self.author.key(),
             title,
             content,
             treasury,
             amount_required,
+            kind,
+            round: 0,
             upvotes: 0,
             downvotes: 0,
-            created_at: Clock::get()?.unix_timestamp,
-            status: Status::Pending,
+            abstentions: 0,
+            voters_count: 0,
+            options,
+            winning_option: None,
+            runoff_round: 0,
+            runoff_ballots: 0,
+            conviction: 0,
+            conviction_updated_at: 0,
+            approved_at: 0,
+            vetoes: 0,
+            council_size: 0,
+            funding_approvals: 0,
+            electorate: 0,
+            sponsors: 0,
+            created_at: now,
+            voting_opened_at: 0,
+            status: Status::Draft,
 
             bump: bumps.proposal,
         });
 
+        if self.dao_config.proposal_deposit > 0 {
+            let cpi_ctx = CpiContext::new(
+                self.system_program.to_account_info(),
+                Transfer {
+                    from: self.author.to_account_info(),
+                    to: self.proposal_deposit.to_account_info(),
+                },
+            );
+            transfer(cpi_ctx, self.dao_config.proposal_deposit)?;
+        }
+
+        self.author_member.open_proposals = self
+            .author_member
+            .open_proposals
+            .checked_add(1)
+            .ok_or(ProthesisError::CountOutOfRange)?;
+        self.author_member.last_proposal_at = now;
+
+        self.dao_config.proposal_count = self
+            .dao_config
+            .proposal_count
+            .checked_add(1)
+            .ok_or(ProthesisError::CountOutOfRange)?;
+
         Ok(())
     }
 }
@@ -0,0 +1,100 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{DAO_CONFIG_SEED, PROPOSAL_SEED, TREASURY_SEED},
+    error::ProthesisError,
+    state::{DAOConfig, Proposal, ProposalKind, Status},
+    utils::transfer_from_treasury,
+};
+
+#[derive(Accounts)]
+pub struct UpdateConviction<'info> {
+    // Anybody can accrue the conviction of a proposal and fund it once it passes
+    #[account(mut)]
+    pub cranker: Signer<'info>,
+
+    #[account(
+        seeds = [DAO_CONFIG_SEED, dao_config.id.to_le_bytes().as_ref()],
+        bump = dao_config.bump,
+    )]
+    pub dao_config: Account<'info, DAOConfig>,
+
+    #[account(
+        mut,
+        seeds = [PROPOSAL_SEED, proposal.id.to_le_bytes().as_ref(), dao_config.key().as_ref()],
+        bump = proposal.bump,
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    #[account(
+        mut,
+        seeds = [TREASURY_SEED, dao_config.key().as_ref()],
+        bump = dao_config.treasury_bump
+    )]
+    pub dao_treasury: SystemAccount<'info>,
+
+    /// CHECK: This is validated with the treasury field in the proposal struct
+    #[account(mut)]
+    pub proposal_treasury: SystemAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> UpdateConviction<'info> {
+    /// Accrues the conviction of a pending proposal, approving it once it passes, and funds an
+    /// approved proposal once its veto period and the execution delay have elapsed
+    pub fn update_conviction(&mut self) -> Result<()> {
+        require!(
+            self.proposal.kind == ProposalKind::Conviction,
+            ProthesisError::WrongProposalKind
+        );
+
+        let now = Clock::get()?.unix_timestamp;
+        match self.proposal.status {
+            Status::Pending => {
+                self.proposal
+                    .accrue_conviction(now, self.dao_config.conviction_half_life)?;
+
+                // The threshold rises with the share of the treasury requested
+                let threshold = self.dao_config.conviction_threshold(
+                    self.proposal.electorate,
+                    self.proposal.amount_required,
+                    self.dao_treasury.lamports(),
+                );
+
+                // Without enough conviction yet, only the accrued conviction is saved
+                if threshold.is_some_and(|threshold| self.proposal.conviction as u128 >= threshold)
+                {
+                    self.proposal.status = Status::Approved;
+                    self.proposal.approved_at = now;
+                }
+            }
+            Status::Approved => {}
+            _ => return Err(ProthesisError::ProposalNotPending.into()),
+        }
+
+        if self.proposal.status == Status::Approved
+            && now
+                >= self
+                    .dao_config
+                    .funding_unlocked_at(self.proposal.approved_at)
+        {
+            require!(
+                self.proposal_treasury.key() == self.proposal.treasury,
+                ProthesisError::MismatchedTreasuryAccount
+            );
+
+            transfer_from_treasury(
+                &self.dao_config,
+                &self.dao_treasury,
+                self.proposal_treasury.to_account_info(),
+                &self.system_program,
+                self.proposal.amount_required,
+            )?;
+
+            self.proposal.status = Status::Executed;
+        }
+
+        Ok(())
+    }
+}
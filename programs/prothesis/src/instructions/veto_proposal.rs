@@ -0,0 +1,101 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{DAO_CONFIG_SEED, MAX_VETO_REASON_LENGTH, MEMBER_SEED, PROPOSAL_SEED, VETO_SEED},
+    error::ProthesisError,
+    events::ProposalVetoed,
+    state::{DAOConfig, Member, Proposal, Status, Veto},
+};
+
+#[derive(Accounts)]
+pub struct VetoProposal<'info> {
+    #[account(mut)]
+    pub vetoer: Signer<'info>,
+
+    #[account(
+        seeds = [DAO_CONFIG_SEED, dao_config.id.to_le_bytes().as_ref()],
+        bump = dao_config.bump,
+    )]
+    pub dao_config: Account<'info, DAOConfig>,
+
+    #[account(
+        seeds = [MEMBER_SEED, vetoer.key().as_ref(), dao_config.key().as_ref()],
+        bump = council_member.bump,
+        constraint = council_member.is_council @ ProthesisError::NotCouncilMember
+    )]
+    pub council_member: Account<'info, Member>,
+
+    #[account(
+        mut,
+        seeds = [PROPOSAL_SEED, proposal.id.to_le_bytes().as_ref(), dao_config.key().as_ref()],
+        bump = proposal.bump,
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    #[account(
+        init,
+        payer = vetoer,
+        seeds = [VETO_SEED, council_member.key().as_ref(), proposal.key().as_ref()],
+        bump,
+        space = Veto::SPACE
+    )]
+    pub veto: Account<'info, Veto>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> VetoProposal<'info> {
+    /// Vetoes an approved proposal during its veto period. The proposal is vetoed once
+    /// `veto_pct` of the council members from when voting opened have vetoed it.
+    pub fn veto_proposal(&mut self, reason: String, bumps: &VetoProposalBumps) -> Result<()> {
+        require!(
+            reason.len() <= MAX_VETO_REASON_LENGTH,
+            ProthesisError::VetoReasonTooLong
+        );
+        require!(
+            self.proposal.status == Status::Approved,
+            ProthesisError::ProposalNotApproved
+        );
+
+        let now = Clock::get()?.unix_timestamp;
+        require!(
+            now < self.dao_config.veto_deadline(self.proposal.approved_at),
+            ProthesisError::VetoPeriodEnded
+        );
+
+        // Only council members counted in the council snapshot can veto
+        require!(
+            self.council_member.council_since <= self.proposal.voting_opened_at,
+            ProthesisError::VoterNotEligible
+        );
+
+        self.proposal.vetoes = self
+            .proposal
+            .vetoes
+            .checked_add(1)
+            .ok_or(ProthesisError::CountOutOfRange)?;
+
+        if self
+            .dao_config
+            .reaches_veto(self.proposal.vetoes, self.proposal.council_size)
+        {
+            self.proposal.status = Status::Vetoed;
+
+            emit!(ProposalVetoed {
+                proposal: self.proposal.key(),
+                vetoes: self.proposal.vetoes,
+                reason: reason.clone(),
+            });
+        }
+
+        self.veto.set_inner(Veto {
+            council_member: self.council_member.key(),
+            proposal: self.proposal.key(),
+            reason,
+            vetoed_at: now,
+            bump: bumps.veto,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,110 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{BALLOT_SEED, DAO_CONFIG_SEED, MEMBER_SEED, PROPOSAL_SEED},
+    error::ProthesisError,
+    state::{Ballot, DAOConfig, Member, Proposal, ProposalKind, Status},
+};
+
+#[derive(Accounts)]
+pub struct VoteOnOptions<'info> {
+    #[account(mut)]
+    pub voter: Signer<'info>,
+
+    #[account(
+        seeds = [DAO_CONFIG_SEED, dao_config.id.to_le_bytes().as_ref()],
+        bump = dao_config.bump,
+    )]
+    pub dao_config: Account<'info, DAOConfig>,
+
+    #[account(
+        mut,
+        seeds = [PROPOSAL_SEED, proposal.id.to_le_bytes().as_ref(), dao_config.key().as_ref()],
+        bump = proposal.bump,
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    #[account(
+        seeds = [MEMBER_SEED, voter.key().as_ref(), dao_config.key().as_ref()],
+        bump = voter_member.bump
+    )]
+    pub voter_member: Account<'info, Member>,
+
+    #[account(
+        init,
+        payer = voter,
+        seeds = [BALLOT_SEED, voter_member.key().as_ref(), proposal.key().as_ref()],
+        bump,
+        space = Ballot::SPACE
+    )]
+    pub ballot: Account<'info, Ballot>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> VoteOnOptions<'info> {
+    /// Casts a ballot on a multiple-choice proposal, picking exactly one option, or on a
+    /// ranked-choice proposal, ranking any number of options from the most to the least preferred
+    pub fn vote_on_options(&mut self, choices: Vec<u8>, bumps: &VoteOnOptionsBumps) -> Result<()> {
+        require!(
+            self.proposal.status == Status::Pending,
+            ProthesisError::ProposalNotPending
+        );
+        require!(
+            self.proposal.kind.has_options(),
+            ProthesisError::WrongProposalKind
+        );
+
+        // Only members counted in the electorate snapshot can vote
+        require!(
+            self.voter_member.joined_at <= self.proposal.voting_opened_at,
+            ProthesisError::VoterNotEligible
+        );
+
+        // Choice proposals are only counted once voting has ended, so late ballots would be ignored
+        require!(
+            Clock::get()?.unix_timestamp
+                < self
+                    .dao_config
+                    .voting_deadline(self.proposal.voting_opened_at),
+            ProthesisError::VotingPeriodEnded
+        );
+
+        let options_count = self.proposal.options.len();
+        let max_choices = match self.proposal.kind {
+            ProposalKind::MultipleChoice => 1,
+            _ => options_count,
+        };
+        require!(
+            (1..=max_choices).contains(&choices.len()),
+            ProthesisError::InvalidChoices
+        );
+        for (rank, choice) in choices.iter().enumerate() {
+            require!(
+                (*choice as usize) < options_count && !choices[..rank].contains(choice),
+                ProthesisError::InvalidChoices
+            );
+        }
+
+        // The first choice is tallied right away, later rounds of ranked-choice counts are run on review
+        let first_choice = &mut self.proposal.options[choices[0] as usize];
+        first_choice.votes = first_choice
+            .votes
+            .checked_add(1)
+            .ok_or(ProthesisError::CountOutOfRange)?;
+        self.proposal.voters_count = self
+            .proposal
+            .voters_count
+            .checked_add(1)
+            .ok_or(ProthesisError::CountOutOfRange)?;
+
+        self.ballot.set_inner(Ballot {
+            voter: self.voter_member.key(),
+            choices,
+            counted_round: 0,
+            bump: bumps.ballot,
+        });
+
+        Ok(())
+    }
+}
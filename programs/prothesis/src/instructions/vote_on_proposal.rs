@@ -1,9 +1,9 @@
 use anchor_lang::prelude::*;
 
 use crate::{
-    constants::{DAO_CONFIG_SEED, MEMBER_SEED, PROPOSAL_SEED, VOTE_SEED},
+    constants::{DAO_CONFIG_SEED, GOVERNANCE_DEPOSIT_SEED, MEMBER_SEED, PROPOSAL_SEED, VOTE_SEED},
     error::ProthesisError,
-    state::{DAOConfig, Member, Proposal, Status, Vote, VoteType},
+    state::{DAOConfig, GovernanceDeposit, Member, Proposal, Status, Vote, VoteType},
 };
 
 #[derive(Accounts)]
@@ -19,7 +19,7 @@ pub struct VoteOnProposal<'info> {
 
     #[account(
         mut,
-        seeds = [PROPOSAL_SEED, proposal.title.as_bytes().as_ref(), dao_config.key().as_ref()],
+        seeds = [PROPOSAL_SEED, proposal.id.to_le_bytes().as_ref(), dao_config.key().as_ref()],
         bump = proposal.bump,
     )]
     pub proposal: Account<'info, Proposal>,
@@ -39,6 +39,14 @@ pub struct VoteOnProposal<'info> {
     )]
     pub vote: Account<'info, Vote>,
 
+    /// The voter's governance token deposit, required in token-weighted mode
+    #[account(
+        mut,
+        seeds = [GOVERNANCE_DEPOSIT_SEED, voter.key().as_ref(), dao_config.key().as_ref()],
+        bump = voter_deposit.bump,
+    )]
+    pub voter_deposit: Option<Account<'info, GovernanceDeposit>>,
+
     pub system_program: Program<'info, System>,
 }
 
@@ -49,28 +57,25 @@ impl<'info> VoteOnProposal<'info> {
             ProthesisError::ProposalNotPending
         );
 
-        self.vote.vote_type = match vote {
-            0 => VoteType::Downvote,
-            1 => VoteType::Upvote,
-            _ => return Err(ProthesisError::InvalidVoteType.into()),
-        };
+        // Only members counted in the electorate snapshot can vote
+        require!(
+            self.voter_member.joined_at <= self.proposal.voting_opened_at,
+            ProthesisError::VoterNotEligible
+        );
+
+        let vote_type = VoteType::try_from(vote)?;
+        let weight = self.proposal.cast_vote(
+            &self.dao_config,
+            self.voter_deposit.as_deref_mut(),
+            &vote_type,
+        )?;
 
-        match self.vote.vote_type {
-            VoteType::Downvote => {
-                self.proposal.downvotes = self
-                    .proposal
-                    .downvotes
-                    .checked_add(1)
-                    .ok_or(ProthesisError::CountOutOfRange)?
-            }
-            VoteType::Upvote => {
-                self.proposal.upvotes = self
-                    .proposal
-                    .upvotes
-                    .checked_add(1)
-                    .ok_or(ProthesisError::CountOutOfRange)?
-            }
-        }
+        self.vote.set_inner(Vote {
+            vote_type,
+            weight,
+            cast_by: None,
+            payer: self.voter.key(),
+        });
 
         Ok(())
     }
@@ -19,7 +19,7 @@ pub struct VoteOnRoleOp<'info> {
 
     #[account(
         mut,
-        seeds = [role_op.seed.as_ref(), role_op.member.as_ref(), dao_config.key().as_ref()],
+        seeds = [role_op.seed.as_ref(), role_op.member.as_ref(), dao_config.key().as_ref(), role_op.id.to_le_bytes().as_ref()],
         bump = role_op.bump
     )]
     pub role_op: Account<'info, RoleOp>,
@@ -44,40 +44,36 @@ pub struct VoteOnRoleOp<'info> {
 
 impl<'info> VoteOnRoleOp<'info> {
     pub fn vote_on_role_op(&mut self, vote: u8) -> Result<()> {
-        // Account checks for the different role operations
+        // Account checks for the different role operations, where only members counted in the
+        // electorate snapshot can vote
         match self.role_op.op_type {
             RoleOpType::PromoteToCouncil | RoleOpType::DemoteFromCouncil => {
                 require!(
                     self.voter_member.is_council,
                     ProthesisError::NotCouncilMember,
                 ); // Only council members can vote for promotion or demotion
+                require!(
+                    self.voter_member.council_since <= self.role_op.created_at,
+                    ProthesisError::VoterNotEligible
+                );
             }
-            RoleOpType::RemoveMember => {} // Do nothing, anybody can vote for member removal
-        }
-
-        self.vote.vote_type = match vote {
-            0 => VoteType::Downvote,
-            1 => VoteType::Upvote,
-            _ => return Err(ProthesisError::InvalidVoteType.into()),
-        };
-
-        match self.vote.vote_type {
-            VoteType::Downvote => {
-                self.role_op.downvotes = self
-                    .role_op
-                    .downvotes
-                    .checked_add(1)
-                    .ok_or(ProthesisError::CountOutOfRange)?
-            }
-            VoteType::Upvote => {
-                self.role_op.upvotes = self
-                    .role_op
-                    .upvotes
-                    .checked_add(1)
-                    .ok_or(ProthesisError::CountOutOfRange)?
+            RoleOpType::RemoveMember => {
+                // Anybody can vote for member removal
+                require!(
+                    self.voter_member.joined_at <= self.role_op.created_at,
+                    ProthesisError::VoterNotEligible
+                );
             }
         }
 
+        self.vote.set_inner(Vote {
+            vote_type: VoteType::try_from(vote)?,
+            weight: 1,
+            cast_by: None,
+            payer: self.voter.key(),
+        });
+        self.role_op.add_vote(&self.vote.vote_type)?;
+
         Ok(())
     }
 }
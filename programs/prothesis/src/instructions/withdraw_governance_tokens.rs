@@ -0,0 +1,91 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+use crate::{
+    constants::{DAO_CONFIG_SEED, GOVERNANCE_DEPOSIT_SEED, GOVERNANCE_VAULT_SEED},
+    error::ProthesisError,
+    state::{DAOConfig, GovernanceDeposit},
+};
+
+#[derive(Accounts)]
+pub struct WithdrawGovernanceTokens<'info> {
+    pub withdrawer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [DAO_CONFIG_SEED, dao_config.id.to_le_bytes().as_ref()],
+        bump = dao_config.bump,
+    )]
+    pub dao_config: Account<'info, DAOConfig>,
+
+    #[account(
+        constraint = dao_config.governance_mint == Some(governance_mint.key()) @ ProthesisError::InvalidGovernanceMint
+    )]
+    pub governance_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        token::mint = governance_mint,
+        token::token_program = token_program,
+    )]
+    pub withdrawer_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [GOVERNANCE_VAULT_SEED, dao_config.key().as_ref()],
+        bump,
+    )]
+    pub governance_vault: InterfaceAccount<'info, TokenAccount>,
+
+    // Keyed by wallet rather than member, so tokens can be withdrawn after leaving the DAO
+    #[account(
+        mut,
+        seeds = [GOVERNANCE_DEPOSIT_SEED, withdrawer.key().as_ref(), dao_config.key().as_ref()],
+        bump = deposit.bump,
+    )]
+    pub deposit: Account<'info, GovernanceDeposit>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> WithdrawGovernanceTokens<'info> {
+    pub fn withdraw_governance_tokens(&mut self, amount: u64) -> Result<()> {
+        require!(
+            !self.deposit.is_locked(Clock::get()?.unix_timestamp),
+            ProthesisError::GovernanceTokensLocked
+        );
+        require!(
+            amount <= self.deposit.amount,
+            ProthesisError::InsufficientGovernanceTokens
+        );
+
+        let cpi_accounts = TransferChecked {
+            from: self.governance_vault.to_account_info(),
+            mint: self.governance_mint.to_account_info(),
+            to: self.withdrawer_token_account.to_account_info(),
+            authority: self.dao_config.to_account_info(),
+        };
+
+        let dao_id = self.dao_config.id.to_le_bytes();
+        let seeds: &[&[&[u8]]] = &[&[DAO_CONFIG_SEED, dao_id.as_ref(), &[self.dao_config.bump]]];
+
+        let cpi_ctx =
+            CpiContext::new_with_signer(self.token_program.to_account_info(), cpi_accounts, seeds);
+        transfer_checked(cpi_ctx, amount, self.governance_mint.decimals)?;
+
+        self.deposit.amount = self
+            .deposit
+            .amount
+            .checked_sub(amount)
+            .ok_or(ProthesisError::CountOutOfRange)?;
+        self.dao_config.total_governance_tokens = self
+            .dao_config
+            .total_governance_tokens
+            .checked_sub(amount)
+            .ok_or(ProthesisError::CountOutOfRange)?;
+
+        Ok(())
+    }
+}
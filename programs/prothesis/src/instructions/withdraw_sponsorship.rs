@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{DAO_CONFIG_SEED, MEMBER_SEED, SPONSORSHIP_SEED},
+    error::ProthesisError,
+    state::{DAOConfig, Proposal, Sponsorship, Status},
+};
+
+#[derive(Accounts)]
+pub struct WithdrawSponsorship<'info> {
+    #[account(mut)]
+    pub sponsor: Signer<'info>,
+
+    #[account(
+        seeds = [DAO_CONFIG_SEED, dao_config.id.to_le_bytes().as_ref()],
+        bump = dao_config.bump,
+    )]
+    pub dao_config: Account<'info, DAOConfig>,
+
+    /// CHECK: Only used to derive the sponsorship PDA, so sponsorships can be closed after leaving the DAO
+    #[account(
+        seeds = [MEMBER_SEED, sponsor.key().as_ref(), dao_config.key().as_ref()],
+        bump,
+    )]
+    pub sponsor_member: UncheckedAccount<'info>,
+
+    /// CHECK: The sponsored proposal. It is validated in the handler since it may already be closed.
+    #[account(mut)]
+    pub proposal: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        close = sponsor,
+        seeds = [SPONSORSHIP_SEED, sponsor_member.key().as_ref(), proposal.key().as_ref()],
+        bump = sponsorship.bump,
+        constraint = sponsorship.proposal == proposal.key() @ ProthesisError::InvalidSponsorship
+    )]
+    pub sponsorship: Account<'info, Sponsorship>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> WithdrawSponsorship<'info> {
+    /// Withdraws the sponsorship of a draft proposal, refunding the rent to the sponsor. Once voting
+    /// has opened the sponsorship no longer counts, and closing it only reclaims the rent.
+    pub fn withdraw_sponsorship(&mut self) -> Result<()> {
+        // Resolved proposals are closed, so there is nothing left to sponsor
+        if self.proposal.owner != &crate::ID || self.proposal.data_is_empty() {
+            return Ok(());
+        }
+
+        let mut proposal = Proposal::try_deserialize(&mut &self.proposal.try_borrow_data()?[..])?;
+        if proposal.status == Status::Draft {
+            proposal.sponsors = proposal
+                .sponsors
+                .checked_sub(1)
+                .ok_or(ProthesisError::CountOutOfRange)?;
+            proposal.try_serialize(&mut &mut self.proposal.try_borrow_mut_data()?[..])?;
+        }
+
+        Ok(())
+    }
+}
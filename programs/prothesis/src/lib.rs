@@ -1,7 +1,20 @@
+// Anchor generates its IDL instruction handlers, which still call the deprecated
+// `AccountInfo::realloc`, in a crate-root module that item attributes cannot reach.
+// Deprecations are allowed for the crate root only and warned about again in every module below.
+#![allow(deprecated)]
+
+#[warn(deprecated)]
 pub mod constants;
+#[warn(deprecated)]
 pub mod error;
+#[warn(deprecated)]
+pub mod events;
+#[warn(deprecated)]
 pub mod instructions;
+#[warn(deprecated)]
 pub mod state;
+#[warn(deprecated)]
+pub mod utils;
 
 use anchor_lang::prelude::*;
 
@@ -12,24 +25,13 @@ pub use state::*;
 declare_id!("5Smo5qyWNHkaQ5KouJ8X1em8Zwwy3Bvwz3DWELjaZQkp");
 
 #[program]
+#[warn(deprecated)]
 pub mod prothesis {
 
     use super::*;
 
-    pub fn initialize_dao(
-        ctx: Context<InitializeDAO>,
-        id: u64,
-        consensus_pct: u16,
-        consensus_lifetime: i64,
-        min_multisig_signers: u64,
-    ) -> Result<()> {
-        ctx.accounts.initialize_dao(
-            id,
-            consensus_pct,
-            consensus_lifetime,
-            min_multisig_signers,
-            &ctx.bumps,
-        )
+    pub fn initialize_dao(ctx: Context<InitializeDAO>, id: u64, params: DAOParams) -> Result<()> {
+        ctx.accounts.initialize_dao(id, params, &ctx.bumps)
     }
 
     pub fn fund_dao(ctx: Context<FundDAO>, amount: u64) -> Result<()> {
@@ -41,7 +43,7 @@ pub mod prothesis {
     }
 
     pub fn exit_dao(ctx: Context<ExitDao>) -> Result<()> {
-        ctx.accounts.exit_dao()
+        ctx.accounts.exit_dao(ctx.remaining_accounts.to_vec())
     }
 
     pub fn initiate_role_op(ctx: Context<InitiateRoleOp>, role_op_seed: Vec<u8>) -> Result<()> {
@@ -57,18 +59,27 @@ pub mod prothesis {
     }
 
     pub fn resolve_role_op(ctx: Context<ResolveRoleOp>) -> Result<()> {
-        ctx.accounts.resolve_role_op()
+        ctx.accounts.resolve_role_op(&ctx.bumps)
     }
 
     pub fn submit_proposal(
         ctx: Context<SubmitProposal>,
         title: String,
-        content: String,
+        content: ProposalContent,
         treasury: Pubkey,
         amount_required: u64,
+        kind: ProposalKind,
+        options: Vec<ProposalOptionParams>,
     ) -> Result<()> {
-        ctx.accounts
-            .submit_proposal(title, content, treasury, amount_required, &ctx.bumps)
+        ctx.accounts.submit_proposal(
+            title,
+            content,
+            treasury,
+            amount_required,
+            kind,
+            options,
+            &ctx.bumps,
+        )
     }
 
     pub fn vote_on_proposal(ctx: Context<VoteOnProposal>, vote: u8) -> Result<()> {
@@ -76,11 +87,146 @@ pub mod prothesis {
     }
 
     pub fn review_proposal(ctx: Context<ReviewProposal>) -> Result<()> {
-        ctx.accounts.review_proposal()
+        ctx.accounts
+            .review_proposal(ctx.remaining_accounts.to_vec())
+    }
+
+    pub fn resolve_proposal(
+        ctx: Context<ResolveProposal>,
+        expected_content_hash: Option<[u8; 32]>,
+    ) -> Result<()> {
+        ctx.accounts.resolve_proposal(
+            expected_content_hash,
+            ctx.remaining_accounts.to_vec(),
+            &ctx.bumps,
+        )
+    }
+
+    pub fn approve_funding(ctx: Context<ApproveFunding>) -> Result<()> {
+        ctx.accounts.approve_funding(&ctx.bumps)
+    }
+
+    pub fn revoke_funding_approval(ctx: Context<RevokeFundingApproval>) -> Result<()> {
+        ctx.accounts.revoke_funding_approval()
+    }
+
+    pub fn execute_funding(ctx: Context<ExecuteFunding>) -> Result<()> {
+        ctx.accounts
+            .execute_funding(ctx.remaining_accounts.to_vec())
+    }
+
+    pub fn close_vote(ctx: Context<CloseVote>) -> Result<()> {
+        ctx.accounts.close_vote(ctx.remaining_accounts.to_vec())
+    }
+
+    pub fn change_vote_on_proposal(ctx: Context<ChangeVoteOnProposal>, vote: u8) -> Result<()> {
+        ctx.accounts.change_vote_on_proposal(vote)
     }
 
-    pub fn resolve_proposal(ctx: Context<ResolveProposal>) -> Result<()> {
+    pub fn retract_vote_on_proposal(ctx: Context<RetractVoteOnProposal>) -> Result<()> {
+        ctx.accounts.retract_vote_on_proposal()
+    }
+
+    pub fn change_vote_on_role_op(ctx: Context<ChangeVoteOnRoleOp>, vote: u8) -> Result<()> {
+        ctx.accounts.change_vote_on_role_op(vote)
+    }
+
+    pub fn retract_vote_on_role_op(ctx: Context<RetractVoteOnRoleOp>) -> Result<()> {
+        ctx.accounts.retract_vote_on_role_op()
+    }
+
+    pub fn delegate_vote(ctx: Context<DelegateVote>) -> Result<()> {
+        ctx.accounts.delegate_vote(&ctx.bumps)
+    }
+
+    pub fn revoke_delegation(ctx: Context<RevokeDelegation>) -> Result<()> {
+        ctx.accounts.revoke_delegation()
+    }
+
+    pub fn cast_delegated_votes<'info>(
+        ctx: Context<'_, '_, '_, 'info, CastDelegatedVotes<'info>>,
+    ) -> Result<()> {
         ctx.accounts
-            .resolve_proposal(ctx.remaining_accounts.to_vec())
+            .cast_delegated_votes(ctx.remaining_accounts.to_vec())
+    }
+
+    pub fn deposit_governance_tokens(
+        ctx: Context<DepositGovernanceTokens>,
+        amount: u64,
+    ) -> Result<()> {
+        ctx.accounts.deposit_governance_tokens(amount, &ctx.bumps)
+    }
+
+    pub fn withdraw_governance_tokens(
+        ctx: Context<WithdrawGovernanceTokens>,
+        amount: u64,
+    ) -> Result<()> {
+        ctx.accounts.withdraw_governance_tokens(amount)
+    }
+
+    pub fn quadratic_vote(ctx: Context<QuadraticVote>, vote: u8, votes: u64) -> Result<()> {
+        ctx.accounts.quadratic_vote(vote, votes, &ctx.bumps)
+    }
+
+    pub fn update_conviction(ctx: Context<UpdateConviction>) -> Result<()> {
+        ctx.accounts.update_conviction()
+    }
+
+    pub fn commit_vote(ctx: Context<CommitVote>, hash: [u8; 32]) -> Result<()> {
+        ctx.accounts.commit_vote(hash, &ctx.bumps)
+    }
+
+    pub fn reveal_vote(ctx: Context<RevealVote>, vote: u8, salt: [u8; 32]) -> Result<()> {
+        ctx.accounts.reveal_vote(vote, salt)
+    }
+
+    pub fn close_vote_commitment(ctx: Context<CloseVoteCommitment>) -> Result<()> {
+        ctx.accounts.close_vote_commitment()
+    }
+
+    pub fn relay_vote(ctx: Context<RelayVote>, vote: u8, nonce: u64) -> Result<()> {
+        ctx.accounts.relay_vote(vote, nonce)
+    }
+
+    pub fn vote_on_options(ctx: Context<VoteOnOptions>, choices: Vec<u8>) -> Result<()> {
+        ctx.accounts.vote_on_options(choices, &ctx.bumps)
+    }
+
+    pub fn close_ballot(ctx: Context<CloseBallot>) -> Result<()> {
+        ctx.accounts.close_ballot()
+    }
+
+    pub fn veto_proposal(ctx: Context<VetoProposal>, reason: String) -> Result<()> {
+        ctx.accounts.veto_proposal(reason, &ctx.bumps)
+    }
+
+    pub fn close_veto(ctx: Context<CloseVeto>) -> Result<()> {
+        ctx.accounts.close_veto()
+    }
+
+    pub fn cancel_proposal(ctx: Context<CancelProposal>) -> Result<()> {
+        ctx.accounts.cancel_proposal()
+    }
+
+    pub fn edit_proposal(
+        ctx: Context<EditProposal>,
+        content: ProposalContent,
+        treasury: Pubkey,
+        amount_required: u64,
+    ) -> Result<()> {
+        ctx.accounts
+            .edit_proposal(content, treasury, amount_required)
+    }
+
+    pub fn open_voting(ctx: Context<OpenVoting>) -> Result<()> {
+        ctx.accounts.open_voting(ctx.remaining_accounts.to_vec())
+    }
+
+    pub fn sponsor_proposal(ctx: Context<SponsorProposal>) -> Result<()> {
+        ctx.accounts.sponsor_proposal(&ctx.bumps)
+    }
+
+    pub fn withdraw_sponsorship(ctx: Context<WithdrawSponsorship>) -> Result<()> {
+        ctx.accounts.withdraw_sponsorship()
     }
 }
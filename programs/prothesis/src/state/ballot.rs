@@ -0,0 +1,22 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_PROPOSAL_OPTIONS;
+
+/// Ballot account - a member's choices on a multiple-choice or ranked-choice proposal
+#[account]
+#[derive(InitSpace)]
+pub struct Ballot {
+    /// Member account that cast the ballot
+    pub voter: Pubkey,
+    /// Indexes of the options chosen, from the most to the least preferred
+    #[max_len(MAX_PROPOSAL_OPTIONS)]
+    pub choices: Vec<u8>,
+    /// Last instant-runoff round the ballot was counted in, so that it is only counted once per round
+    pub counted_round: u8,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl Ballot {
+    pub const SPACE: usize = 8 + Ballot::INIT_SPACE;
+}
@@ -1,21 +1,74 @@
 use anchor_lang::prelude::*;
 
+use crate::constants::CONVICTION_PRECISION;
+
+use super::ProposalKind;
+
+/// Enum representing where the rent of a closed proposal is refunded to
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace, PartialEq, Eq)]
+pub enum RentDestination {
+    /// The wallet that submitted the proposal
+    Author,
+    /// The DAO treasury, as a small contribution to the DAO
+    Treasury,
+}
+
 /// DAO configuration account - singleton that stores governance parameters
 #[account]
 #[derive(InitSpace)]
 pub struct DAOConfig {
     /// DAO id differentiator
     pub id: u64,
-    /// Minimum percentage of upvotes/downvote required for approval/dismissal, as basis point (e.g., 1000 = 10%)
+    /// Minimum percentage of the votes cast, as upvotes/downvotes, required for approval/dismissal, as basis point (e.g., 1000 = 10%)
     pub consensus_pct: u16,
+    /// Minimum percentage of the electorate that must vote for an outcome to be decided, as basis point
+    pub quorum_pct: u16,
     /// Lifetime of a proposal/promotion in seconds (e.g., 604800 = 7 days)
     pub consensus_lifetime: i64,
     /// Number of dao members
     pub members_count: u64,
     /// Number of dao council members
     pub council_count: u64,
+    /// Number of proposals submitted so far, used as the ID of the next proposal
+    pub proposal_count: u64,
+    /// Number of role operations initiated so far, used as the ID of the next role operation
+    pub role_op_count: u64,
     /// Number of multisig signers required to execute a treasury withdrawal
     pub min_multisig_signers: u64,
+    /// Where the rent of resolved proposals is refunded to
+    pub proposal_rent_destination: RentDestination,
+    /// Mint of the governance token, proposal votes are weighted by the tokens deposited when set
+    pub governance_mint: Option<Pubkey>,
+    /// Total amount of governance tokens deposited by members, the electorate of token-weighted proposals
+    pub total_governance_tokens: u64,
+    /// Voice credits each member can spend on quadratic proposals per round, 0 disables quadratic voting
+    pub voice_credits_per_round: u64,
+    /// Duration of a voice credit round in seconds
+    pub voice_credit_round_duration: i64,
+    /// Seconds for the conviction of a proposal to close half of the gap to its staked support, 0 disables conviction voting
+    pub conviction_half_life: i64,
+    /// Conviction required to fund a proposal requesting nothing, as basis points of the electorate, to which
+    /// the share of the treasury balance requested is added
+    pub conviction_min_pct: u16,
+    /// Seconds after the voting period of standard proposals during which committed votes are revealed,
+    /// 0 disables commit-reveal voting
+    pub reveal_lifetime: i64,
+    /// Seconds after a proposal is approved during which the council can veto it, 0 disables vetoes
+    pub veto_period: i64,
+    /// Minimum percentage of the council members that must veto a proposal to block it, as basis points
+    pub veto_pct: u16,
+    /// Seconds after a proposal is approved before it can be funded, giving members who disagree time to exit
+    pub execution_delay: i64,
+    /// Number of members other than the author who must sponsor a draft proposal before voting can open
+    pub min_sponsors: u64,
+    /// Lamports held in escrow for each submitted proposal and returned once it is resolved
+    pub proposal_deposit: u64,
+    /// Whether the deposit of dismissed proposals is forfeited to the treasury instead of returned
+    pub forfeit_dismissed_deposits: bool,
+    /// Maximum number of open proposals per member, 0 means unlimited
+    pub max_open_proposals_per_member: u64,
+    /// Minimum time in seconds between two proposals of the same member
+    pub proposal_cooldown: i64,
     /// PDA bump
     pub bump: u8,
     /// Treasury bump
@@ -24,4 +77,88 @@ pub struct DAOConfig {
 
 impl DAOConfig {
     pub const SPACE: usize = 8 + DAOConfig::INIT_SPACE;
+
+    /// Whether proposal votes are weighted by deposited governance tokens rather than one per member
+    pub fn is_token_weighted(&self) -> bool {
+        self.governance_mint.is_some()
+    }
+
+    /// Voice credit round that `timestamp` falls in
+    pub fn voice_credit_round(&self, timestamp: i64) -> u64 {
+        (timestamp / self.voice_credit_round_duration) as u64
+    }
+
+    /// Conviction, scaled by `CONVICTION_PRECISION`, a proposal needs to be funded `amount` out of the
+    /// `treasury_balance`, or `None` if the treasury cannot fund it
+    pub fn conviction_threshold(
+        &self,
+        electorate: u64,
+        amount: u64,
+        treasury_balance: u64,
+    ) -> Option<u128> {
+        if amount >= treasury_balance {
+            return None;
+        }
+
+        let threshold_pct =
+            self.conviction_min_pct as u128 + amount as u128 * 10_000 / treasury_balance as u128;
+        Some((electorate as u128 * CONVICTION_PRECISION as u128 * threshold_pct).div_ceil(10_000))
+    }
+
+    /// Unix timestamp at which votes on a proposal or role op whose voting started at `started_at` are no longer counted
+    pub fn voting_deadline(&self, started_at: i64) -> i64 {
+        started_at.saturating_add(self.consensus_lifetime)
+    }
+
+    /// Whether votes on proposals of the given kind are committed as hashes and revealed after the voting period
+    pub fn uses_commit_reveal(&self, kind: &ProposalKind) -> bool {
+        self.reveal_lifetime > 0 && *kind == ProposalKind::Standard
+    }
+
+    /// Unix timestamp at which the reveal phase of a proposal whose voting started at `started_at` ends
+    pub fn reveal_deadline(&self, started_at: i64) -> i64 {
+        self.voting_deadline(started_at)
+            .saturating_add(self.reveal_lifetime)
+    }
+
+    /// Unix timestamp at which the veto period of a proposal approved at `approved_at` ends
+    pub fn veto_deadline(&self, approved_at: i64) -> i64 {
+        approved_at.saturating_add(self.veto_period)
+    }
+
+    /// Unix timestamp from which a proposal approved at `approved_at` can be funded, once both its
+    /// veto period and the execution delay have elapsed
+    pub fn funding_unlocked_at(&self, approved_at: i64) -> i64 {
+        self.veto_deadline(approved_at)
+            .max(approved_at.saturating_add(self.execution_delay))
+    }
+
+    /// Most votes a member can cast on a single quadratic proposal, the square root of their voice credits
+    pub fn max_quadratic_votes(&self) -> u64 {
+        let (mut low, mut high) = (0u64, self.voice_credits_per_round.min(u32::MAX as u64));
+        while low < high {
+            let mid = low + (high - low).div_ceil(2);
+            if mid * mid <= self.voice_credits_per_round {
+                low = mid;
+            } else {
+                high = mid - 1;
+            }
+        }
+        low
+    }
+
+    /// Whether `vetoes` make up at least `veto_pct` of the `council_size` council members
+    pub fn reaches_veto(&self, vetoes: u64, council_size: u64) -> bool {
+        vetoes as u128 * 10_000 >= self.veto_pct as u128 * council_size as u128
+    }
+
+    /// Minimum number (or weight) of votes to be cast out of the `electorate` to reach quorum, rounded up
+    pub fn quorum(&self, electorate: u64) -> u64 {
+        (self.quorum_pct as u128 * electorate as u128).div_ceil(10_000) as u64
+    }
+
+    /// Whether `votes` make up at least `consensus_pct` of the `votes_cast`
+    pub fn reaches_consensus(&self, votes: u64, votes_cast: u64) -> bool {
+        votes_cast > 0 && votes as u128 * 10_000 >= self.consensus_pct as u128 * votes_cast as u128
+    }
 }
@@ -0,0 +1,19 @@
+use anchor_lang::prelude::*;
+
+/// Delegation account - lets a member's vote on proposals be cast by another member
+#[account]
+#[derive(InitSpace)]
+pub struct Delegation {
+    /// The member account delegating its vote
+    pub delegator: Pubkey,
+    /// The member account voting on the delegator's behalf
+    pub delegate: Pubkey,
+    /// Unix timestamp when the delegation was made
+    pub delegated_at: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl Delegation {
+    pub const SPACE: usize = 8 + Delegation::INIT_SPACE;
+}
@@ -0,0 +1,19 @@
+use anchor_lang::prelude::*;
+
+/// FundingApproval account - records a council member's approval to fund an approved proposal
+#[account]
+#[derive(InitSpace)]
+pub struct FundingApproval {
+    /// The council member account that approved the funding
+    pub approver: Pubkey,
+    /// The proposal whose funding is approved
+    pub proposal: Pubkey,
+    /// Unix timestamp when the approval was given
+    pub approved_at: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl FundingApproval {
+    pub const SPACE: usize = 8 + FundingApproval::INIT_SPACE;
+}
@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+use crate::error::ProthesisError;
+
+/// GovernanceDeposit account - tracks the governance tokens a wallet deposited to vote in a token-weighted DAO
+#[account]
+#[derive(InitSpace)]
+pub struct GovernanceDeposit {
+    /// Wallet that deposited the tokens and can withdraw them
+    pub owner: Pubkey,
+    /// Amount of governance tokens deposited, the weight of the owner's votes
+    pub amount: u64,
+    /// Unix timestamp until which the tokens back votes on pending proposals and cannot be withdrawn
+    pub locked_until: i64,
+    /// Number of votes backed by the tokens, which are unlocked early once all of them are retracted
+    pub locked_votes: u64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl GovernanceDeposit {
+    pub const SPACE: usize = 8 + GovernanceDeposit::INIT_SPACE;
+
+    /// Locks the tokens behind one more vote, until at least `until`
+    pub fn lock_vote(&mut self, until: i64) -> Result<()> {
+        self.locked_until = self.locked_until.max(until);
+        self.locked_votes = self
+            .locked_votes
+            .checked_add(1)
+            .ok_or(ProthesisError::CountOutOfRange)?;
+        Ok(())
+    }
+
+    /// Releases the lock of a retracted vote
+    pub fn release_vote(&mut self) {
+        self.locked_votes = self.locked_votes.saturating_sub(1);
+    }
+
+    /// Whether the tokens still back votes whose voting period has not ended
+    pub fn is_locked(&self, now: i64) -> bool {
+        self.locked_votes > 0 && now < self.locked_until
+    }
+}
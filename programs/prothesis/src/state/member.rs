@@ -10,10 +10,32 @@ pub struct Member {
     pub is_council: bool,
     /// Time the member joined
     pub joined_at: i64,
+    /// Time the member last became a council member, 0 if they never were
+    pub council_since: i64,
+    /// Nonce the next relayed vote message of this member must carry, so that signed votes cannot be replayed
+    pub relay_nonce: u64,
+    /// Number of proposals submitted by this member that are not resolved or cancelled yet
+    pub open_proposals: u64,
+    /// Unix timestamp of the last proposal submitted by this member
+    pub last_proposal_at: i64,
+    /// Number of votes a delegate cast for this member that are not closed yet, which keep the member from exiting
+    pub delegated_votes: u64,
     /// PDA bump
     pub bump: u8,
 }
 
 impl Member {
     pub const SPACE: usize = 8 + Member::INIT_SPACE;
+
+    /// Stops counting one of the member's proposals as open. Saturates, since a member who exited
+    /// and joined again starts over with no open proposals.
+    pub fn close_proposal(&mut self) {
+        self.open_proposals = self.open_proposals.saturating_sub(1);
+    }
+
+    /// Stops counting one of the votes a delegate cast for the member, once it is closed or taken
+    /// over by the member
+    pub fn close_delegated_vote(&mut self) {
+        self.delegated_votes = self.delegated_votes.saturating_sub(1);
+    }
 }
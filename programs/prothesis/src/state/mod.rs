@@ -1,13 +1,33 @@
+pub mod ballot;
 pub mod dao_config;
+pub mod delegation;
+pub mod funding_approval;
+pub mod governance_deposit;
 pub mod member;
 pub mod proposal;
+pub mod proposal_receipt;
 pub mod role_op;
+pub mod role_op_receipt;
+pub mod sponsorship;
 pub mod status;
+pub mod veto;
+pub mod voice_credit_ledger;
 pub mod vote;
+pub mod vote_commitment;
 
+pub use ballot::*;
 pub use dao_config::*;
+pub use delegation::*;
+pub use funding_approval::*;
+pub use governance_deposit::*;
 pub use member::*;
 pub use proposal::*;
+pub use proposal_receipt::*;
 pub use role_op::*;
+pub use role_op_receipt::*;
+pub use sponsorship::*;
 pub use status::*;
+pub use veto::*;
+pub use voice_credit_ledger::*;
 pub use vote::*;
+pub use vote_commitment::*;
@@ -1,31 +1,183 @@
-use anchor_lang::prelude::*;
+use anchor_lang::{prelude::*, solana_program::hash::hash};
 
-use crate::constants::{MAX_CONTENT_LENGTH, MAX_TITLE_LENGTH};
+use crate::{
+    constants::{
+        CONVICTION_PRECISION, MAX_CONTENT_LENGTH, MAX_CONTENT_URI_LENGTH, MAX_OPTION_LABEL_LENGTH,
+        MAX_PROPOSAL_OPTIONS, MAX_TITLE_LENGTH,
+    },
+    error::ProthesisError,
+};
 
-use super::Status;
+use super::{DAOConfig, GovernanceDeposit, Status, VoteType};
+
+/// Enum representing how a proposal is voted on
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace, PartialEq, Eq)]
+pub enum ProposalKind {
+    /// One vote per member, or weighted by deposited governance tokens in token-weighted DAOs
+    Standard,
+    /// Members spend voice credits of the proposal's round, N votes costing N² credits
+    Quadratic,
+    /// Members stake their support, which accrues conviction over time until the proposal is funded
+    Conviction,
+    /// Members pick one of the proposal's options, the option with the most votes wins
+    MultipleChoice,
+    /// Members rank the proposal's options, the winner is found by instant-runoff
+    RankedChoice,
+}
+
+impl ProposalKind {
+    /// Whether proposals of this kind are voted on by choosing among their options
+    pub fn has_options(&self) -> bool {
+        matches!(
+            self,
+            ProposalKind::MultipleChoice | ProposalKind::RankedChoice
+        )
+    }
+}
+
+/// An option of a multiple-choice or ranked-choice proposal
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct ProposalOption {
+    /// Label of the option (max 32 chars)
+    #[max_len(MAX_OPTION_LABEL_LENGTH)]
+    pub label: String,
+    /// The account funded if the option wins
+    pub treasury: Pubkey,
+    /// How much the option needs if it wins (in Lamports), 0 if it needs no funding
+    pub amount: u64,
+    /// Number of members who picked the option, or ranked it first in ranked-choice proposals
+    pub votes: u64,
+    /// Whether the option was eliminated from the instant-runoff count of a ranked-choice proposal
+    pub eliminated: bool,
+    /// Ballots counted for the option in the current instant-runoff round
+    pub runoff_votes: u64,
+}
+
+/// Result of finishing an instant-runoff round of a ranked-choice proposal
+#[derive(PartialEq, Eq)]
+pub enum RunoffRound {
+    /// Ballots of the current round are still to be counted
+    Counting,
+    /// The option with the fewest votes was eliminated, and the ballots are counted again
+    Eliminated,
+    /// The option won with a consensus among all voters
+    Winner(u8),
+    /// No option can reach a consensus among all voters
+    NoWinner,
+}
+
+/// Content of a proposal, either stored in the proposal or off-chain
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub enum ProposalContent {
+    /// Content stored in the proposal itself (max 2048 chars)
+    Inline {
+        #[max_len(MAX_CONTENT_LENGTH)]
+        text: String,
+    },
+    /// Content stored off-chain at `uri` (max 200 chars), committed to by its SHA-256 `hash`
+    External {
+        #[max_len(MAX_CONTENT_URI_LENGTH)]
+        uri: String,
+        hash: [u8; 32],
+    },
+}
+
+impl ProposalContent {
+    pub fn validate(&self) -> Result<()> {
+        match self {
+            ProposalContent::Inline { text } => require!(
+                text.len() <= MAX_CONTENT_LENGTH,
+                ProthesisError::ContentTooLong
+            ),
+            ProposalContent::External { uri, .. } => require!(
+                !uri.is_empty() && uri.len() <= MAX_CONTENT_URI_LENGTH,
+                ProthesisError::InvalidContentUri
+            ),
+        }
+
+        Ok(())
+    }
+
+    /// Serialized size of the content, so that proposals only pay rent for the content they hold
+    pub fn space(&self) -> usize {
+        1 + match self {
+            ProposalContent::Inline { text } => 4 + text.len(),
+            ProposalContent::External { uri, .. } => 4 + uri.len() + 32,
+        }
+    }
+
+    /// SHA-256 hash of the content, the committed hash of off-chain content
+    pub fn hash(&self) -> [u8; 32] {
+        match self {
+            ProposalContent::Inline { text } => hash(text.as_bytes()).to_bytes(),
+            ProposalContent::External { hash, .. } => *hash,
+        }
+    }
+}
 
 /// Proposal account - stores a single proposal's data and voting status
 #[account]
 #[derive(InitSpace)]
 pub struct Proposal {
+    /// Sequential ID of the proposal within the DAO
+    pub id: u64,
     /// Author of the proposal
     pub author: Pubkey,
+    /// Wallet of the author, refunded the rent when the DAO is configured to do so
+    pub author_wallet: Pubkey,
     /// Title of the proposal (max 64 chars)
     #[max_len(MAX_TITLE_LENGTH)]
     pub title: String,
-    /// Content of the proposal (max 2048 chars)
-    #[max_len(MAX_CONTENT_LENGTH)]
-    pub content: String,
+    /// Content of the proposal, inline or off-chain
+    pub content: ProposalContent,
     /// How much is needed for the proposal (in Lamports)
     pub amount_required: u64,
-    /// Number of upvotes received
+    /// How the proposal is voted on
+    pub kind: ProposalKind,
+    /// Voice credit round the proposal belongs to, only meaningful for quadratic proposals
+    pub round: u64,
+    /// Number of upvotes received, or their total weight in token-weighted mode
     pub upvotes: u64,
-    /// Number of downvotes received
+    /// Number of downvotes received, or their total weight in token-weighted mode
     pub downvotes: u64,
-    /// Unix timestamp when the proposal was created
+    /// Number of abstentions received, or their total weight in token-weighted mode
+    pub abstentions: u64,
+    /// Number of members who voted, the turnout of quadratic proposals
+    pub voters_count: u64,
+    /// Options of a multiple-choice or ranked-choice proposal, empty for other proposals
+    #[max_len(MAX_PROPOSAL_OPTIONS)]
+    pub options: Vec<ProposalOption>,
+    /// Index of the winning option once a multiple-choice or ranked-choice proposal is approved
+    pub winning_option: Option<u8>,
+    /// Instant-runoff round a ranked-choice proposal is counted in, the number of options eliminated so far
+    pub runoff_round: u8,
+    /// Number of ballots counted in the current instant-runoff round
+    pub runoff_ballots: u64,
+    /// Conviction accrued by a conviction proposal, scaled by `CONVICTION_PRECISION`
+    pub conviction: u64,
+    /// Unix timestamp when the conviction was last accrued
+    pub conviction_updated_at: i64,
+    /// Number of DAO members when voting opened, or the governance tokens deposited for standard
+    /// proposals in token-weighted mode, the electorate its votes are tallied against
+    pub electorate: u64,
+    /// Number of members currently sponsoring the proposal
+    pub sponsors: u64,
+    /// Unix timestamp when the proposal was submitted as a draft
     pub created_at: i64,
+    /// Unix timestamp when voting opened, 0 while the proposal is a draft. Members who joined later cannot vote on it.
+    pub voting_opened_at: i64,
     /// The account where the proposal funding will go to
     pub treasury: Pubkey,
+    /// Unix timestamp when the proposal was approved, 0 while it is not
+    pub approved_at: i64,
+    /// Number of council members who vetoed the proposal during its veto period
+    pub vetoes: u64,
+    /// Number of council members when voting opened, the council its vetoes are counted against.
+    /// Council members promoted later cannot veto it.
+    pub council_size: u64,
+    /// Number of funding approvals currently open on the proposal, whose approvers are only checked
+    /// to still be council members when the funding is executed
+    pub funding_approvals: u64,
     /// Current status of the proposal
     pub status: Status,
     /// PDA bump
@@ -34,4 +186,241 @@ pub struct Proposal {
 
 impl Proposal {
     pub const SPACE: usize = 8 + Proposal::INIT_SPACE;
+
+    /// Space of a proposal holding `content`, every other field is sized for its maximum length
+    pub fn space_for(content: &ProposalContent) -> usize {
+        Proposal::SPACE - ProposalContent::INIT_SPACE + content.space()
+    }
+
+    /// Counts a vote of the given weight towards the proposal's tally
+    pub fn add_vote(&mut self, vote_type: &VoteType, weight: u64) -> Result<()> {
+        let count = match vote_type {
+            VoteType::Downvote => &mut self.downvotes,
+            VoteType::Upvote => &mut self.upvotes,
+            VoteType::Abstain => &mut self.abstentions,
+        };
+        *count = count
+            .checked_add(weight)
+            .ok_or(ProthesisError::CountOutOfRange)?;
+        Ok(())
+    }
+
+    /// Most votes, by weight, that the members who have not voted yet could still add to the tally
+    pub fn outstanding_votes(&self, dao_config: &DAOConfig) -> u64 {
+        let voters_left = self.electorate.saturating_sub(self.voters_count);
+        match self.kind {
+            ProposalKind::Standard if dao_config.is_token_weighted() => {
+                // Tokens deposited since voting opened can be voted with as well
+                let turnout = self
+                    .upvotes
+                    .saturating_add(self.downvotes)
+                    .saturating_add(self.abstentions);
+                self.electorate
+                    .max(dao_config.total_governance_tokens)
+                    .saturating_sub(turnout)
+            }
+            ProposalKind::Quadratic => voters_left.saturating_mul(dao_config.max_quadratic_votes()),
+            _ => voters_left,
+        }
+    }
+
+    /// Counts a member's direct vote on a standard or conviction proposal and returns its weight.
+    /// In token-weighted mode the vote is weighted by the voter's deposit, which stays locked until
+    /// voting ends so that the tokens cannot back another member's vote too.
+    pub fn cast_vote(
+        &mut self,
+        dao_config: &DAOConfig,
+        deposit: Option<&mut GovernanceDeposit>,
+        vote_type: &VoteType,
+    ) -> Result<u64> {
+        let weight = match self.kind {
+            // Quadratic and choice proposals are voted on with voice credits and ballots instead
+            ProposalKind::Quadratic | ProposalKind::MultipleChoice | ProposalKind::RankedChoice => {
+                return Err(ProthesisError::WrongProposalKind.into())
+            }
+            ProposalKind::Conviction => {
+                require!(
+                    *vote_type == VoteType::Upvote,
+                    ProthesisError::ConvictionSupportOnly
+                );
+
+                // Accrue the conviction of the support staked so far before adding to it
+                self.accrue_conviction(
+                    Clock::get()?.unix_timestamp,
+                    dao_config.conviction_half_life,
+                )?;
+                1
+            }
+            ProposalKind::Standard if dao_config.uses_commit_reveal(&self.kind) => {
+                return Err(ProthesisError::VotesMustBeCommitted.into())
+            }
+            ProposalKind::Standard if dao_config.is_token_weighted() => {
+                let voting_deadline = dao_config.voting_deadline(self.voting_opened_at);
+                require!(
+                    Clock::get()?.unix_timestamp < voting_deadline,
+                    ProthesisError::VotingPeriodEnded
+                );
+
+                let deposit = deposit.ok_or(ProthesisError::GovernanceDepositRequired)?;
+                require!(deposit.amount > 0, ProthesisError::NoVotingWeight);
+                deposit.lock_vote(voting_deadline)?;
+
+                deposit.amount
+            }
+            ProposalKind::Standard => 1,
+        };
+
+        self.add_vote(vote_type, weight)?;
+        self.voters_count = self
+            .voters_count
+            .checked_add(1)
+            .ok_or(ProthesisError::CountOutOfRange)?;
+
+        Ok(weight)
+    }
+
+    /// Accrues the conviction up to `now`. Conviction moves towards the support currently staked
+    /// (the upvotes), closing half of the gap every `half_life` seconds. The decay within a
+    /// half-life is approximated linearly.
+    pub fn accrue_conviction(&mut self, now: i64, half_life: i64) -> Result<()> {
+        let elapsed = now.saturating_sub(self.conviction_updated_at).max(0) as u128;
+        let half_life = half_life as u128;
+        let precision = CONVICTION_PRECISION as u128;
+
+        // Share of the previous conviction that is retained, scaled by the precision
+        let halvings = elapsed / half_life;
+        let remainder = elapsed % half_life;
+        let retained = if halvings >= 64 {
+            0
+        } else {
+            (precision * (2 * half_life - remainder) / (2 * half_life)) >> halvings
+        };
+
+        let support = self.upvotes as u128 * precision;
+        let conviction =
+            (self.conviction as u128 * retained + support * (precision - retained)) / precision;
+
+        self.conviction = u64::try_from(conviction).map_err(|_| ProthesisError::CountOutOfRange)?;
+        self.conviction_updated_at = now;
+        Ok(())
+    }
+
+    /// Option with the most votes, if no other option has as many and it reaches consensus among the voters
+    pub fn plurality_winner(&self, dao_config: &DAOConfig) -> Option<u8> {
+        let counts: Vec<u64> = self.options.iter().map(|option| option.votes).collect();
+        let continuing = vec![true; counts.len()];
+        Self::leading_option(&counts, &continuing)
+            .filter(|&winner| dao_config.reaches_consensus(counts[winner], self.voters_count))
+            .map(|winner| winner as u8)
+    }
+
+    /// Counts a ballot, listing option indexes from the most to the least preferred, in the current
+    /// instant-runoff round for its most preferred option still in the running
+    pub fn count_runoff_ballot(&mut self, choices: &[u8]) -> Result<()> {
+        if let Some(&choice) = choices
+            .iter()
+            .find(|&&choice| !self.options[choice as usize].eliminated)
+        {
+            let option = &mut self.options[choice as usize];
+            option.runoff_votes = option
+                .runoff_votes
+                .checked_add(1)
+                .ok_or(ProthesisError::CountOutOfRange)?;
+        }
+
+        self.runoff_ballots = self
+            .runoff_ballots
+            .checked_add(1)
+            .ok_or(ProthesisError::CountOutOfRange)?;
+        Ok(())
+    }
+
+    /// Finishes the current instant-runoff round once every ballot has been counted in it. The first
+    /// round is the first preferences tallied as the ballots were cast, so it needs no ballots. The
+    /// leading option wins once it reaches consensus among all voters. Otherwise the option with the
+    /// fewest votes is eliminated, the last one listed on ties, and the next round starts.
+    pub fn finish_runoff_round(&mut self, dao_config: &DAOConfig) -> RunoffRound {
+        if self.runoff_round > 0 && self.runoff_ballots < self.voters_count {
+            return RunoffRound::Counting;
+        }
+
+        let counts: Vec<u64> = self
+            .options
+            .iter()
+            .map(|option| {
+                if self.runoff_round == 0 {
+                    option.votes
+                } else {
+                    option.runoff_votes
+                }
+            })
+            .collect();
+        let continuing: Vec<bool> = self
+            .options
+            .iter()
+            .map(|option| !option.eliminated)
+            .collect();
+
+        if let Some(leader) = Self::leading_option(&counts, &continuing) {
+            if dao_config.reaches_consensus(counts[leader], self.voters_count) {
+                return RunoffRound::Winner(leader as u8);
+            }
+        }
+
+        if continuing.iter().filter(|&&running| running).count() <= 1 {
+            return RunoffRound::NoWinner;
+        }
+
+        let Some((eliminated, _)) = counts
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| continuing[*index])
+            .rev()
+            .min_by_key(|(_, count)| **count)
+        else {
+            return RunoffRound::NoWinner;
+        };
+        self.options[eliminated].eliminated = true;
+
+        for option in self.options.iter_mut() {
+            option.runoff_votes = 0;
+        }
+        self.runoff_ballots = 0;
+        self.runoff_round += 1;
+
+        RunoffRound::Eliminated
+    }
+
+    /// Continuing option with strictly more votes than any other continuing option
+    fn leading_option(counts: &[u64], continuing: &[bool]) -> Option<usize> {
+        let mut running = counts
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| continuing[*index]);
+        let (mut leader, mut leader_count) = running.next()?;
+        let mut tied = false;
+
+        for (index, count) in running {
+            if count > leader_count {
+                (leader, leader_count, tied) = (index, count, false);
+            } else if count == leader_count {
+                tied = true;
+            }
+        }
+
+        (!tied).then_some(leader)
+    }
+
+    /// Removes a previously counted vote of the given weight from the proposal's tally
+    pub fn remove_vote(&mut self, vote_type: &VoteType, weight: u64) -> Result<()> {
+        let count = match vote_type {
+            VoteType::Downvote => &mut self.downvotes,
+            VoteType::Upvote => &mut self.upvotes,
+            VoteType::Abstain => &mut self.abstentions,
+        };
+        *count = count
+            .checked_sub(weight)
+            .ok_or(ProthesisError::CountOutOfRange)?;
+        Ok(())
+    }
 }
@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+use super::Status;
+
+/// ProposalReceipt account - compact record of a proposal's outcome, kept after the proposal is closed
+#[account]
+#[derive(InitSpace)]
+pub struct ProposalReceipt {
+    /// The closed proposal account
+    pub proposal: Pubkey,
+    /// Author of the proposal
+    pub author: Pubkey,
+    /// SHA-256 hash of the proposal content, see `ProposalContent::hash`
+    pub content_hash: [u8; 32],
+    /// The account where the proposal funding went or would have gone to
+    pub treasury: Pubkey,
+    /// How much was requested by the proposal (in Lamports)
+    pub amount_required: u64,
+    /// Number of upvotes received, or their total weight in token-weighted mode
+    pub upvotes: u64,
+    /// Number of downvotes received, or their total weight in token-weighted mode
+    pub downvotes: u64,
+    /// Number of abstentions received, or their total weight in token-weighted mode
+    pub abstentions: u64,
+    /// Index of the winning option of a multiple-choice or ranked-choice proposal
+    pub winning_option: Option<u8>,
+    /// Final status of the proposal
+    pub status: Status,
+    /// Unix timestamp when the proposal was created
+    pub created_at: i64,
+    /// Unix timestamp when the proposal was resolved
+    pub resolved_at: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl ProposalReceipt {
+    pub const SPACE: usize = 8 + ProposalReceipt::INIT_SPACE;
+}
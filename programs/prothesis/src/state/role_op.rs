@@ -1,6 +1,8 @@
 use anchor_lang::prelude::*;
 
-use super::Status;
+use crate::error::ProthesisError;
+
+use super::{Status, VoteType};
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace, PartialEq, Eq)]
 pub enum RoleOpType {
@@ -16,6 +18,8 @@ pub enum RoleOpType {
 #[account]
 #[derive(InitSpace)]
 pub struct RoleOp {
+    /// Sequential ID of the role operation within the DAO, so the same member can be nominated repeatedly
+    pub id: u64,
     /// Seed/ID to differentiate the role operations
     #[max_len(9)] // because promotion is the longest of all the options
     pub seed: Vec<u8>,
@@ -27,7 +31,11 @@ pub struct RoleOp {
     pub upvotes: u64,
     /// Number of downvotes received
     pub downvotes: u64,
-    /// Unix timestamp when the RoleOp was created
+    /// Number of abstentions received
+    pub abstentions: u64,
+    /// Number of members who can vote on the RoleOp when it was created, the council for council roles and every member for removals, the electorate its votes are tallied against
+    pub electorate: u64,
+    /// Unix timestamp when the RoleOp was created, members who joined later cannot vote on it
     pub created_at: i64,
     /// Current status of the RoleOp
     pub status: Status,
@@ -37,4 +45,30 @@ pub struct RoleOp {
 
 impl RoleOp {
     pub const SPACE: usize = 8 + RoleOp::INIT_SPACE;
+
+    /// Counts a vote towards the role operation's tally
+    pub fn add_vote(&mut self, vote_type: &VoteType) -> Result<()> {
+        let count = match vote_type {
+            VoteType::Downvote => &mut self.downvotes,
+            VoteType::Upvote => &mut self.upvotes,
+            VoteType::Abstain => &mut self.abstentions,
+        };
+        *count = count
+            .checked_add(1)
+            .ok_or(ProthesisError::CountOutOfRange)?;
+        Ok(())
+    }
+
+    /// Removes a previously counted vote from the role operation's tally
+    pub fn remove_vote(&mut self, vote_type: &VoteType) -> Result<()> {
+        let count = match vote_type {
+            VoteType::Downvote => &mut self.downvotes,
+            VoteType::Upvote => &mut self.upvotes,
+            VoteType::Abstain => &mut self.abstentions,
+        };
+        *count = count
+            .checked_sub(1)
+            .ok_or(ProthesisError::CountOutOfRange)?;
+        Ok(())
+    }
 }
@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+use super::{RoleOpType, Status};
+
+/// RoleOpReceipt account - record of a role operation's outcome, kept after the role operation is closed
+#[account]
+#[derive(InitSpace)]
+pub struct RoleOpReceipt {
+    /// The closed role operation account
+    pub role_op: Pubkey,
+    /// The type of operation
+    pub op_type: RoleOpType,
+    /// The target member of the role operation
+    pub member: Pubkey,
+    /// Number of upvotes received
+    pub upvotes: u64,
+    /// Number of downvotes received
+    pub downvotes: u64,
+    /// Number of abstentions received
+    pub abstentions: u64,
+    /// Final status of the role operation
+    pub status: Status,
+    /// Unix timestamp when the role operation was created
+    pub created_at: i64,
+    /// Unix timestamp when the role operation was resolved
+    pub resolved_at: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl RoleOpReceipt {
+    pub const SPACE: usize = 8 + RoleOpReceipt::INIT_SPACE;
+}
@@ -0,0 +1,19 @@
+use anchor_lang::prelude::*;
+
+/// Sponsorship account - records a member sponsoring a draft proposal
+#[account]
+#[derive(InitSpace)]
+pub struct Sponsorship {
+    /// Member account sponsoring the proposal
+    pub sponsor: Pubkey,
+    /// The sponsored proposal
+    pub proposal: Pubkey,
+    /// Unix timestamp when the proposal was sponsored
+    pub sponsored_at: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl Sponsorship {
+    pub const SPACE: usize = 8 + Sponsorship::INIT_SPACE;
+}
@@ -11,4 +11,14 @@ pub enum Status {
     Dismissed,
     /// Proposal/role op has expired due to reaching its lifetime without resolution
     Expired,
+    /// Proposal/role op has reached its lifetime without enough members voting
+    QuorumNotReached,
+    /// Proposal has been funded from the DAO treasury
+    Executed,
+    /// Proposal has been vetoed by a council supermajority during its veto period
+    Vetoed,
+    /// Proposal has been cancelled by its author before voting ended
+    Cancelled,
+    /// Proposal is being drafted by its author and cannot be voted on yet
+    Draft,
 }
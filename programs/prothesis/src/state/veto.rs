@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_VETO_REASON_LENGTH;
+
+/// Veto account - a council member's veto of an approved proposal
+#[account]
+#[derive(InitSpace)]
+pub struct Veto {
+    /// Council member account that vetoed the proposal
+    pub council_member: Pubkey,
+    /// The vetoed proposal
+    pub proposal: Pubkey,
+    /// Why the council member vetoed the proposal (max 256 chars)
+    #[max_len(MAX_VETO_REASON_LENGTH)]
+    pub reason: String,
+    /// Unix timestamp when the veto was cast
+    pub vetoed_at: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl Veto {
+    pub const SPACE: usize = 8 + Veto::INIT_SPACE;
+}
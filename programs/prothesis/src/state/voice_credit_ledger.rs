@@ -0,0 +1,19 @@
+use anchor_lang::prelude::*;
+
+/// VoiceCreditLedger account - tracks the voice credits a member spent on quadratic proposals in a round
+#[account]
+#[derive(InitSpace)]
+pub struct VoiceCreditLedger {
+    /// The member account spending the credits
+    pub member: Pubkey,
+    /// The voice credit round
+    pub round: u64,
+    /// Voice credits spent so far in the round
+    pub credits_spent: u64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl VoiceCreditLedger {
+    pub const SPACE: usize = 8 + VoiceCreditLedger::INIT_SPACE;
+}
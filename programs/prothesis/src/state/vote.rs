@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
 
+use crate::{constants::RELAYED_VOTE_DOMAIN, error::ProthesisError};
+
 /// Enum representing the type of vote
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace, PartialEq, Eq)]
 pub enum VoteType {
@@ -7,16 +9,50 @@ pub enum VoteType {
     Upvote,
     /// A downvote for a proposal
     Downvote,
+    /// An abstention, counted towards the quorum but not the approval ratio
+    Abstain,
+}
+
+impl TryFrom<u8> for VoteType {
+    type Error = Error;
+
+    fn try_from(vote: u8) -> Result<Self> {
+        match vote {
+            0 => Ok(VoteType::Downvote),
+            1 => Ok(VoteType::Upvote),
+            2 => Ok(VoteType::Abstain),
+            _ => Err(ProthesisError::InvalidVoteType.into()),
+        }
+    }
 }
 
 /// Vote account - tracks a single user's vote on a specific proposal
 #[account]
 #[derive(InitSpace)]
 pub struct Vote {
-    /// The type of vote cast (Up or Down)
+    /// The type of vote cast (Up, Down or Abstain)
     pub vote_type: VoteType,
+    /// Weight the vote was counted with, the voter's deposited governance tokens in token-weighted mode and 1 otherwise
+    pub weight: u64,
+    /// The delegate member account that cast this vote on the voter's behalf, if it was not cast directly
+    pub cast_by: Option<Pubkey>,
+    /// The account that paid the vote's rent, which is refunded to it when the vote is closed
+    pub payer: Pubkey,
 }
 
 impl Vote {
     pub const SPACE: usize = 8 + Vote::INIT_SPACE;
+
+    /// Off-chain message a member signs to have a relayer cast their vote: the domain prefix,
+    /// the DAO config and proposal, the vote type and the member's relay nonce
+    pub fn relay_message(dao_config: &Pubkey, proposal: &Pubkey, vote: u8, nonce: u64) -> Vec<u8> {
+        [
+            RELAYED_VOTE_DOMAIN,
+            dao_config.as_ref(),
+            proposal.as_ref(),
+            &[vote],
+            &nonce.to_le_bytes(),
+        ]
+        .concat()
+    }
 }
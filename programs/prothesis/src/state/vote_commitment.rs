@@ -0,0 +1,25 @@
+use anchor_lang::{prelude::*, solana_program::hash::hashv};
+
+/// VoteCommitment account - a member's hidden vote on a proposal, revealed after the voting period
+#[account]
+#[derive(InitSpace)]
+pub struct VoteCommitment {
+    /// Hash of the vote, see `VoteCommitment::hash`
+    pub hash: [u8; 32],
+    /// Weight the vote will be counted with once revealed
+    pub weight: u64,
+    /// Unix timestamp when the vote was committed
+    pub committed_at: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl VoteCommitment {
+    pub const SPACE: usize = 8 + VoteCommitment::INIT_SPACE;
+
+    /// Commitment to a vote, the SHA-256 hash of the vote type, a secret salt, the voter's member
+    /// account and the proposal, so that commitments cannot be copied by other members
+    pub fn hash(vote: u8, salt: &[u8; 32], voter_member: &Pubkey, proposal: &Pubkey) -> [u8; 32] {
+        hashv(&[&[vote], salt, voter_member.as_ref(), proposal.as_ref()]).to_bytes()
+    }
+}
@@ -0,0 +1,214 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::{
+        ed25519_program,
+        sysvar::instructions::{load_current_index_checked, load_instruction_at_checked},
+    },
+    system_program::{
+        self, allocate, assign, create_account, transfer, Allocate, Assign, CreateAccount, Transfer,
+    },
+};
+
+use crate::{
+    constants::TREASURY_SEED,
+    error::ProthesisError,
+    state::{DAOConfig, Proposal, RoleOp, Status},
+};
+
+/// Transfers `amount` lamports from the DAO treasury, signing with the treasury PDA seeds
+pub fn transfer_from_treasury<'info>(
+    dao_config: &Account<'info, DAOConfig>,
+    dao_treasury: &SystemAccount<'info>,
+    recipient: AccountInfo<'info>,
+    system_program: &Program<'info, System>,
+    amount: u64,
+) -> Result<()> {
+    require!(
+        dao_treasury.to_account_info().lamports() > amount,
+        ProthesisError::InsufficientTreasuryBalance
+    );
+
+    let cpi_program = system_program.to_account_info();
+
+    let cpi_accounts = Transfer {
+        from: dao_treasury.to_account_info(),
+        to: recipient,
+    };
+
+    let dao_config_key = dao_config.key();
+
+    let seeds: &[&[&[u8]]] = &[&[
+        TREASURY_SEED,
+        dao_config_key.as_ref(),
+        &[dao_config.treasury_bump],
+    ]];
+
+    let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, seeds);
+
+    transfer(cpi_ctx, amount)
+}
+
+/// Closes an account owned by this program, sending all of its lamports to `destination`
+pub fn close_account(info: &AccountInfo, destination: &AccountInfo) -> Result<()> {
+    let lamports = info.lamports();
+    destination.add_lamports(lamports)?;
+    info.sub_lamports(lamports)?;
+
+    info.assign(&system_program::ID);
+    info.resize(0)?;
+
+    Ok(())
+}
+
+/// Requires an approved proposal to be past its veto period and execution delay, so that it can be funded
+pub fn require_funding_unlocked(dao_config: &DAOConfig, proposal: &Proposal) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        now >= dao_config.veto_deadline(proposal.approved_at),
+        ProthesisError::VetoPeriodNotEnded
+    );
+    require!(
+        now >= dao_config.funding_unlocked_at(proposal.approved_at),
+        ProthesisError::ExecutionDelayNotElapsed
+    );
+
+    Ok(())
+}
+
+/// Requires the proposal or role op voted on to be finalized, i.e. no longer pending or already closed
+pub fn require_voting_ended(target: &AccountInfo) -> Result<()> {
+    // Resolved proposals and role ops are closed, so there is nothing left to vote on
+    if target.owner != &crate::ID || target.data_is_empty() {
+        return Ok(());
+    }
+
+    let data = target.try_borrow_data()?;
+    let status = if data.starts_with(Proposal::DISCRIMINATOR) {
+        Proposal::try_deserialize(&mut &data[..])?.status
+    } else if data.starts_with(RoleOp::DISCRIMINATOR) {
+        RoleOp::try_deserialize(&mut &data[..])?.status
+    } else {
+        return Err(ProthesisError::InvalidVoteTarget.into());
+    };
+
+    require!(status != Status::Pending, ProthesisError::VotingNotEnded);
+
+    Ok(())
+}
+
+/// Creates a program-owned account at the PDA signed for by `signer_seeds`, paid for by `payer`.
+/// Lamports already sent to the address are kept, so the account cannot be blocked by pre-funding it.
+pub fn create_pda_account<'info>(
+    payer: &AccountInfo<'info>,
+    account: &AccountInfo<'info>,
+    system_program: &Program<'info, System>,
+    space: usize,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let rent = Rent::get()?.minimum_balance(space);
+    let current_lamports = account.lamports();
+
+    if current_lamports == 0 {
+        let cpi_accounts = CreateAccount {
+            from: payer.clone(),
+            to: account.clone(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(
+            system_program.to_account_info(),
+            cpi_accounts,
+            signer_seeds,
+        );
+        return create_account(cpi_ctx, rent, space as u64, &crate::ID);
+    }
+
+    let shortfall = rent.saturating_sub(current_lamports);
+    if shortfall > 0 {
+        let cpi_accounts = Transfer {
+            from: payer.clone(),
+            to: account.clone(),
+        };
+        let cpi_ctx = CpiContext::new(system_program.to_account_info(), cpi_accounts);
+        transfer(cpi_ctx, shortfall)?;
+    }
+
+    let cpi_ctx = CpiContext::new_with_signer(
+        system_program.to_account_info(),
+        Allocate {
+            account_to_allocate: account.clone(),
+        },
+        signer_seeds,
+    );
+    allocate(cpi_ctx, space as u64)?;
+
+    let cpi_ctx = CpiContext::new_with_signer(
+        system_program.to_account_info(),
+        Assign {
+            account_to_assign: account.clone(),
+        },
+        signer_seeds,
+    );
+    assign(cpi_ctx, &crate::ID)
+}
+
+/// Requires the instruction right before the current one to be an ed25519 program instruction
+/// verifying a single signature of `message` by `signer`, with all of its data inline
+pub fn require_ed25519_signature(
+    instructions_sysvar: &AccountInfo,
+    signer: &Pubkey,
+    message: &[u8],
+) -> Result<()> {
+    // Layout of the ed25519 program instruction data with a single signature
+    const HEADER_LEN: usize = 2;
+    const OFFSETS_LEN: usize = 14;
+    const PUBKEY_LEN: usize = 32;
+    const SIGNATURE_LEN: usize = 64;
+
+    let current_index = load_current_index_checked(instructions_sysvar)?;
+    require!(current_index > 0, ProthesisError::InvalidVoteSignature);
+    let instruction =
+        load_instruction_at_checked((current_index - 1) as usize, instructions_sysvar)?;
+
+    require_keys_eq!(
+        instruction.program_id,
+        ed25519_program::ID,
+        ProthesisError::InvalidVoteSignature
+    );
+    let data = &instruction.data;
+    require!(
+        data.len() >= HEADER_LEN + OFFSETS_LEN && data[0] == 1,
+        ProthesisError::InvalidVoteSignature
+    );
+
+    let read_u16 = |at: usize| u16::from_le_bytes([data[at], data[at + 1]]) as usize;
+    let offsets = HEADER_LEN;
+    let signature_offset = read_u16(offsets);
+    let signature_instruction_index = read_u16(offsets + 2);
+    let pubkey_offset = read_u16(offsets + 4);
+    let pubkey_instruction_index = read_u16(offsets + 6);
+    let message_offset = read_u16(offsets + 8);
+    let message_size = read_u16(offsets + 10);
+    let message_instruction_index = read_u16(offsets + 12);
+
+    // The signature, public key and message must all be read from the ed25519 instruction itself
+    let inline = u16::MAX as usize;
+    require!(
+        signature_instruction_index == inline
+            && pubkey_instruction_index == inline
+            && message_instruction_index == inline,
+        ProthesisError::InvalidVoteSignature
+    );
+    require!(
+        signature_offset + SIGNATURE_LEN <= data.len()
+            && pubkey_offset + PUBKEY_LEN <= data.len()
+            && message_offset + message_size <= data.len(),
+        ProthesisError::InvalidVoteSignature
+    );
+
+    require!(
+        &data[pubkey_offset..pubkey_offset + PUBKEY_LEN] == signer.as_ref()
+            && &data[message_offset..message_offset + message_size] == message,
+        ProthesisError::InvalidVoteSignature
+    );
+
+    Ok(())
+}
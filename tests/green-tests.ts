@@ -1,10 +1,11 @@
 import * as anchor from "@coral-xyz/anchor";
 import { Program } from "@coral-xyz/anchor";
 import { Prothesis } from "../target/types/prothesis";
-import { PublicKey, Keypair, LAMPORTS_PER_SOL, SystemProgram } from "@solana/web3.js";
+import { PublicKey, Keypair, LAMPORTS_PER_SOL, SystemProgram, Ed25519Program, SYSVAR_INSTRUCTIONS_PUBKEY } from "@solana/web3.js";
+import { getAccount, TOKEN_2022_PROGRAM_ID, TOKEN_PROGRAM_ID } from "@solana/spl-token";
 import { expect } from "chai";
 
-import { generateSigner, getDAOConfigPDA, getDAOTreasury, getMemberAccount, getProposalPDA, getRoleOpPDA, getVotePDA, } from "./helpers";
+import { createGovernanceMint, createTransferFeeGovernanceMint, generateSigner, getBallotPDA, getDAOConfigPDA, getDAOTreasury, getDelegationPDA, getFundingApprovalPDA, getGovernanceDepositPDA, getGovernanceVaultPDA, getMemberAccount, getProposalDepositPDA, getProposalPDA, getProposalReceiptPDA, getRoleOpPDA, getRoleOpReceiptPDA, getSponsorshipPDA, getVoiceCreditsPDA, getRelayedVoteMessage, getVetoPDA, getVoteCommitmentPDA, getVotePDA, hashProposalContent, hashVoteCommitment, } from "./helpers";
 
 
 describe("prothesis - positive tests", () => {
@@ -23,8 +24,23 @@ describe("prothesis - positive tests", () => {
   // DAO parameters
   const daoId = new anchor.BN(Math.floor(Math.random() * 10_000_000_000));
   const consensusPct = 5100; // 51%
+  const quorumPct = 3000; // 30%
   const consensusLifetime = new anchor.BN(5); // 5 seconds
   const minMultisigSigners = new anchor.BN(3); // At least 3 signers
+  const proposalRentDestination = { author: {} }; // Rent of resolved proposals goes back to the author
+  const voiceCreditsPerRound = new anchor.BN(100); // Voice credits per member for quadratic proposals
+  const voiceCreditRoundDuration = new anchor.BN(30 * 24 * 60 * 60); // 30 days
+  const convictionHalfLife = new anchor.BN(2); // 2 seconds
+  const convictionMinPct = 1000; // 10% of the electorate, plus the share of the treasury requested
+  const revealLifetime = new anchor.BN(0); // Votes are cast in the open
+  const vetoPeriod = new anchor.BN(0); // Approved proposals can be funded right away
+  const vetoPct = 6700; // 67% of the council
+  const executionDelay = new anchor.BN(0); // Approved proposals can be funded right away
+  const minSponsors = new anchor.BN(0); // Drafts can be opened for voting without sponsors
+  const proposalDeposit = new anchor.BN(0); // Proposals can be submitted without a deposit
+  const forfeitDismissedDeposits = false; // Deposits are always returned to the author
+  const maxOpenProposalsPerMember = new anchor.BN(0); // Members can have any number of open proposals
+  const proposalCooldown = new anchor.BN(0); // Members can submit proposals back to back
 
   // PDAs
   let daoConfigPDA: PublicKey;
@@ -40,10 +56,18 @@ describe("prothesis - positive tests", () => {
   let removalRoleOpPDA: PublicKey;
 
   let proposalPDA: PublicKey;
+  let delegationProposalPDA: PublicKey; // Left pending with votes the delegate cast for person3
 
   // Proposal parameters
   const proposalTitle = "Test Proposal";
   const proposalContent = "This is a test proposal for the DAO";
+  const inlineContent = { inline: { text: proposalContent } };
+
+  // Proposals are keyed by the DAO's proposal counter
+  async function getNextProposalPDA(): Promise<PublicKey> {
+    const { proposalCount } = await program.account.daoConfig.fetch(daoConfigPDA);
+    return getProposalPDA(proposalCount, daoConfigPDA);
+  }
 
   before(async () => {
     // Generate Signers
@@ -64,12 +88,13 @@ describe("prothesis - positive tests", () => {
   describe("DAO Initialization and Membership", () => {
     it("Should initialize a new DAO", async () => {
       await program.methods
-        .initializeDao(daoId, consensusPct, consensusLifetime, minMultisigSigners)
+        .initializeDao(daoId, { consensusPct, quorumPct, consensusLifetime, minMultisigSigners, proposalRentDestination, voiceCreditsPerRound, voiceCreditRoundDuration, convictionHalfLife, convictionMinPct, revealLifetime, vetoPeriod, vetoPct, executionDelay, minSponsors, proposalDeposit, forfeitDismissedDeposits, maxOpenProposalsPerMember, proposalCooldown })
         .accountsStrict({
           daoConfig: daoConfigPDA,
           treasury: treasuryPDA,
           creator: creator.publicKey,
           member: creatorMemberAccount,
+          governanceMint: null,
           systemProgram: SystemProgram.programId,
         })
         .signers([creator])
@@ -79,6 +104,7 @@ describe("prothesis - positive tests", () => {
       const daoConfig = await program.account.daoConfig.fetch(daoConfigPDA);
       expect(daoConfig.id.toString()).to.equal(daoId.toString());
       expect(daoConfig.consensusPct).to.equal(consensusPct);
+      expect(daoConfig.quorumPct).to.equal(quorumPct);
       expect(daoConfig.consensusLifetime.toString()).to.equal(consensusLifetime.toString());
       expect(daoConfig.membersCount.toString()).to.equal("1"); // creator is the first member
       expect(daoConfig.councilCount.toString()).to.equal("1"); // creator is a council member
@@ -177,9 +203,10 @@ describe("prothesis - positive tests", () => {
 
   describe("Role Operations", () => {
     before(async () => {
-      promotionRoleOpPDA = getRoleOpPDA("promotion", person1MemberAccount, daoConfigPDA);
-      removalRoleOpPDA = getRoleOpPDA("removal", person1MemberAccount, daoConfigPDA)
-      demotionRoleOpPDA = getRoleOpPDA("demotion", person1MemberAccount, daoConfigPDA)
+      // Role operations are keyed by the DAO's role operation counter, in the order they are initiated
+      promotionRoleOpPDA = getRoleOpPDA("promotion", person1MemberAccount, daoConfigPDA, new anchor.BN(0));
+      removalRoleOpPDA = getRoleOpPDA("removal", person1MemberAccount, daoConfigPDA, new anchor.BN(1))
+      demotionRoleOpPDA = getRoleOpPDA("demotion", person1MemberAccount, daoConfigPDA, new anchor.BN(2))
     })
 
     it("Should initiate a promotion of a member", async () => {
@@ -256,6 +283,7 @@ describe("prothesis - positive tests", () => {
           daoConfig: daoConfigPDA,
           treasury: treasuryPDA,
           roleOp: promotionRoleOpPDA,
+          roleOpReceipt: getRoleOpReceiptPDA(promotionRoleOpPDA),
           nominatedMember: person1MemberAccount,
           resolver: creator.publicKey,
           resolverMember: creatorMemberAccount,
@@ -347,6 +375,28 @@ describe("prothesis - positive tests", () => {
       expect(roleOp.downvotes.toString()).to.equal("2");
     });
 
+    it("Should downvote a removal from a council member", async () => {
+      const roleOpVotePDA = getVotePDA(creatorMemberAccount, removalRoleOpPDA);
+
+      await program.methods
+        .voteOnRoleOp(0)
+        .accountsStrict({
+          daoConfig: daoConfigPDA,
+          roleOp: removalRoleOpPDA,
+          vote: roleOpVotePDA,
+          voter: creator.publicKey,
+          voterMember: creatorMemberAccount,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([creator])
+        .rpc();
+
+      // Verify role op was updated, every member being part of a removal's electorate
+      const roleOp = await program.account.roleOp.fetch(removalRoleOpPDA);
+      expect(roleOp.downvotes.toString()).to.equal("3");
+      expect(roleOp.electorate.toString()).to.equal("4");
+    });
+
     it("Should review a removal", async () => {
       await program.methods
         .reviewRoleOp()
@@ -372,6 +422,7 @@ describe("prothesis - positive tests", () => {
           daoConfig: daoConfigPDA,
           treasury: treasuryPDA,
           roleOp: removalRoleOpPDA,
+          roleOpReceipt: getRoleOpReceiptPDA(removalRoleOpPDA),
           nominatedMember: person1MemberAccount,
           resolver: person2.publicKey,   // Anybody can resolve a removal, not just council members
           resolverMember: person2MemberAccount,
@@ -390,8 +441,6 @@ describe("prothesis - positive tests", () => {
     });
 
     it("Should initiate a demotion for a member", async () => {
-      demotionRoleOpPDA = getRoleOpPDA("demotion", person1MemberAccount, daoConfigPDA)
-
       await program.methods
         .initiateRoleOp(Buffer.from("demotion"))
         .accountsStrict({
@@ -415,7 +464,7 @@ describe("prothesis - positive tests", () => {
       expect(roleOp.downvotes.toString()).to.equal("0");
     });
 
-    it("Should review a demotion", async () => {
+    it("Should review a demotion that nobody voted on", async () => {
       // Wait for the demotion to expire
       await new Promise(resolve => setTimeout(resolve, (consensusLifetime.toNumber() + 2) * 1000)); // Wait for extra 2 seconds (in milliseconds)
 
@@ -431,18 +480,19 @@ describe("prothesis - positive tests", () => {
         .signers([creator])
         .rpc();
 
-      // Verify role op status was updated
+      // Verify role op status was updated, since no council member voted the quorum was not reached
       const roleOp = await program.account.roleOp.fetch(demotionRoleOpPDA);
-      expect(roleOp.status.expired).to.not.be.undefined;
+      expect(roleOp.status.quorumNotReached).to.not.be.undefined;
     });
 
-    it("Should resolve an expired demotion", async () => {
+    it("Should resolve a demotion that did not reach quorum", async () => {
       await program.methods
         .resolveRoleOp()
         .accountsStrict({
           daoConfig: daoConfigPDA,
           treasury: treasuryPDA,
           roleOp: demotionRoleOpPDA,
+          roleOpReceipt: getRoleOpReceiptPDA(demotionRoleOpPDA),
           nominatedMember: person1MemberAccount,
           resolver: creator.publicKey,
           resolverMember: creatorMemberAccount,
@@ -459,21 +509,92 @@ describe("prothesis - positive tests", () => {
       const daoConfig = await program.account.daoConfig.fetch(daoConfigPDA);
       expect(daoConfig.councilCount.toString()).to.equal("2");
     });
+
+    it("Should keep a history of the resolved role operations", async () => {
+      const promotionReceipt = await program.account.roleOpReceipt.fetch(getRoleOpReceiptPDA(promotionRoleOpPDA));
+      expect(promotionReceipt.member.toString()).to.equal(person1MemberAccount.toString());
+      expect("promoteToCouncil" in promotionReceipt.opType).to.be.true;
+      expect(promotionReceipt.status.approved).to.not.be.undefined;
+
+      const removalReceipt = await program.account.roleOpReceipt.fetch(getRoleOpReceiptPDA(removalRoleOpPDA));
+      expect(removalReceipt.status.dismissed).to.not.be.undefined;
+
+      const demotionReceipt = await program.account.roleOpReceipt.fetch(getRoleOpReceiptPDA(demotionRoleOpPDA));
+      expect(demotionReceipt.status.quorumNotReached).to.not.be.undefined;
+    });
+
+    it("Should initiate a repeated demotion of the same member and retract a vote on it", async () => {
+      const { roleOpCount } = await program.account.daoConfig.fetch(daoConfigPDA);
+      const repeatedDemotionRoleOpPDA = getRoleOpPDA("demotion", person1MemberAccount, daoConfigPDA, roleOpCount);
+
+      await program.methods
+        .initiateRoleOp(Buffer.from("demotion"))
+        .accountsStrict({
+          daoConfig: daoConfigPDA,
+          roleOp: repeatedDemotionRoleOpPDA,
+          nominatedMember: person1MemberAccount,
+          councilSigner: creator.publicKey,
+          councilMember: creatorMemberAccount,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([creator])
+        .rpc();
+
+      // Verify a new role op was created for the same member
+      const roleOp = await program.account.roleOp.fetch(repeatedDemotionRoleOpPDA);
+      expect(roleOp.id.toString()).to.equal(roleOpCount.toString());
+      expect(roleOp.member.toString()).to.equal(person1MemberAccount.toString());
+      expect(roleOp.status.pending).to.not.be.undefined;
+
+      // Vote on it and retract the vote while it is still pending
+      const roleOpVotePDA = getVotePDA(creatorMemberAccount, repeatedDemotionRoleOpPDA);
+
+      await program.methods
+        .voteOnRoleOp(1)
+        .accountsStrict({
+          daoConfig: daoConfigPDA,
+          roleOp: repeatedDemotionRoleOpPDA,
+          vote: roleOpVotePDA,
+          voter: creator.publicKey,
+          voterMember: creatorMemberAccount,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([creator])
+        .rpc();
+
+      await program.methods
+        .retractVoteOnRoleOp()
+        .accountsStrict({
+          daoConfig: daoConfigPDA,
+          roleOp: repeatedDemotionRoleOpPDA,
+          vote: roleOpVotePDA,
+          voter: creator.publicKey,
+          voterMember: creatorMemberAccount,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([creator])
+        .rpc();
+
+      // Verify the tally is back to zero
+      const retractedRoleOp = await program.account.roleOp.fetch(repeatedDemotionRoleOpPDA);
+      expect(retractedRoleOp.upvotes.toString()).to.equal("0");
+    });
   });
 
   describe("Proposals", () => {
     before(async () => {
-      proposalPDA = getProposalPDA(proposalTitle, daoConfigPDA);
+      proposalPDA = await getNextProposalPDA();
     })
 
     it("Should submit a proposal", async () => {
       const amount_required = new anchor.BN(0.01 * LAMPORTS_PER_SOL);
 
       await program.methods
-        .submitProposal(proposalTitle, proposalContent, person1.publicKey, amount_required)
+        .submitProposal(proposalTitle, inlineContent, person1.publicKey, amount_required, { standard: {} }, [])
         .accountsStrict({
           daoConfig: daoConfigPDA,
           proposal: proposalPDA,
+          proposalDeposit: getProposalDepositPDA(proposalPDA),
           author: person1.publicKey,
           authorMember: person1MemberAccount,
           systemProgram: SystemProgram.programId,
@@ -481,12 +602,51 @@ describe("prothesis - positive tests", () => {
         .signers([person1])
         .rpc();
 
-      // Verify proposal was created
+      // Verify proposal was created as a draft
       const proposal = await program.account.proposal.fetch(proposalPDA);
       expect(proposal.title).to.equal(proposalTitle);
-      expect(proposal.content).to.equal(proposalContent);
+      expect(proposal.content.inline.text).to.equal(proposalContent);
       expect(proposal.author.toString()).to.equal(person1MemberAccount.toString());
       expect(proposal.treasury.toString()).to.equal(person1.publicKey.toString()); // Treasury was set as the author's wallet
+      expect(proposal.status.draft).to.not.be.undefined;
+    });
+
+    it("Should edit a draft proposal and open it for voting", async () => {
+      const editedContent = proposalContent + " (revised)";
+      const { data: draftData } = await provider.connection.getAccountInfo(proposalPDA);
+
+      await program.methods
+        .editProposal({ inline: { text: editedContent } }, person1.publicKey, new anchor.BN(0.01 * LAMPORTS_PER_SOL))
+        .accountsStrict({
+          author: person1.publicKey,
+          daoConfig: daoConfigPDA,
+          authorMember: person1MemberAccount,
+          proposal: proposalPDA,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([person1])
+        .rpc();
+
+      await program.methods
+        .openVoting()
+        .accountsStrict({
+          author: person1.publicKey,
+          daoConfig: daoConfigPDA,
+          authorMember: person1MemberAccount,
+          proposal: proposalPDA,
+        })
+        .signers([person1])
+        .rpc();
+
+      // Verify the edit was saved and voting opened
+      const proposal = await program.account.proposal.fetch(proposalPDA);
+      expect(proposal.content.inline.text).to.equal(editedContent);
+
+      // Verify the proposal was resized to the edited content
+      const { data: editedData } = await provider.connection.getAccountInfo(proposalPDA);
+      expect(editedData.length).to.equal(draftData.length + " (revised)".length);
+      expect(proposal.electorate.toString()).to.equal("4"); // Snapshot of the members count
+      expect(proposal.votingOpenedAt.toNumber()).to.be.greaterThanOrEqual(proposal.createdAt.toNumber());
       expect(proposal.status.pending).to.not.be.undefined;
     });
 
@@ -501,6 +661,7 @@ describe("prothesis - positive tests", () => {
           vote: proposalVotePDA,
           voter: person1.publicKey,
           voterMember: person1MemberAccount,
+          voterDeposit: null,
           systemProgram: SystemProgram.programId,
         })
         .signers([person1])
@@ -526,6 +687,7 @@ describe("prothesis - positive tests", () => {
           vote: proposalVotePDA,
           voter: creator.publicKey,
           voterMember: creatorMemberAccount,
+          voterDeposit: null,
           systemProgram: SystemProgram.programId,
         })
         .signers([creator])
@@ -551,6 +713,7 @@ describe("prothesis - positive tests", () => {
           vote: proposalVotePDA,
           voter: person3.publicKey,
           voterMember: person3MemberAccount,
+          voterDeposit: null,
           systemProgram: SystemProgram.programId,
         })
         .signers([person3])
@@ -576,6 +739,7 @@ describe("prothesis - positive tests", () => {
           vote: proposalVotePDA,
           voter: person2.publicKey,
           voterMember: person2MemberAccount,
+          voterDeposit: null,
           systemProgram: SystemProgram.programId,
         })
         .signers([person2])
@@ -590,6 +754,91 @@ describe("prothesis - positive tests", () => {
       expect(proposal.upvotes.toString()).to.equal("3");
     });
 
+    it("Should change a vote on a pending proposal", async () => {
+      const proposalVotePDA = getVotePDA(creatorMemberAccount, proposalPDA)
+
+      await program.methods
+        .changeVoteOnProposal(1)
+        .accountsStrict({
+          daoConfig: daoConfigPDA,
+          proposal: proposalPDA,
+          vote: proposalVotePDA,
+          voter: creator.publicKey,
+          voterMember: creatorMemberAccount,
+        })
+        .signers([creator])
+        .rpc();
+
+      // Verify the vote was changed
+      const vote = await program.account.vote.fetch(proposalVotePDA);
+      expect("upvote" in vote.voteType).to.be.true;
+
+      // Verify the tally moved from downvotes to upvotes
+      const proposal = await program.account.proposal.fetch(proposalPDA);
+      expect(proposal.upvotes.toString()).to.equal("4");
+      expect(proposal.downvotes.toString()).to.equal("0");
+    });
+
+    it("Should retract a vote on a pending proposal", async () => {
+      const proposalVotePDA = getVotePDA(person3MemberAccount, proposalPDA)
+
+      await program.methods
+        .retractVoteOnProposal()
+        .accountsStrict({
+          daoConfig: daoConfigPDA,
+          proposal: proposalPDA,
+          vote: proposalVotePDA,
+          rentPayer: person3.publicKey,
+          voter: person3.publicKey,
+          voterMember: person3MemberAccount,
+          voiceCredits: null,
+          voterDeposit: null,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([person3])
+        .rpc();
+
+      // Verify the tally was updated
+      const proposal = await program.account.proposal.fetch(proposalPDA);
+      expect(proposal.upvotes.toString()).to.equal("3");
+
+      // Verify the vote account was closed
+      try {
+        await program.account.vote.fetch(proposalVotePDA);
+        expect.fail("Vote account should be closed");
+      } catch (error) {
+        expect(error.toString()).to.include("Account does not exist");
+      }
+    });
+
+    it("Should abstain on a proposal", async () => {
+      const proposalVotePDA = getVotePDA(person3MemberAccount, proposalPDA)
+
+      await program.methods
+        .voteOnProposal(2)
+        .accountsStrict({
+          daoConfig: daoConfigPDA,
+          proposal: proposalPDA,
+          vote: proposalVotePDA,
+          voter: person3.publicKey,
+          voterMember: person3MemberAccount,
+          voterDeposit: null,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([person3])
+        .rpc();
+
+      // Verify vote was recorded
+      const vote = await program.account.vote.fetch(proposalVotePDA);
+      expect("abstain" in vote.voteType).to.be.true;
+
+      // Verify the abstention was counted separately from the upvotes and downvotes
+      const proposal = await program.account.proposal.fetch(proposalPDA);
+      expect(proposal.abstentions.toString()).to.equal("1");
+      expect(proposal.upvotes.toString()).to.equal("3");
+      expect(proposal.downvotes.toString()).to.equal("0");
+    });
+
     it("Should review a proposal", async () => {
       await program.methods
         .reviewProposal()
@@ -614,16 +863,22 @@ describe("prothesis - positive tests", () => {
 
       const daoTreasuryBalanceBefore = await provider.connection.getBalance(treasuryPDA);
       const proposalTreasuryBalanceBefore = await provider.connection.getBalance(proposalTreasury);
+      const proposalRent = await provider.connection.getBalance(proposalPDA);
 
       await program.methods
-        .resolveProposal()
+        .resolveProposal(null)
         .accountsStrict({
           daoConfig: daoConfigPDA,
           proposal: proposalPDA,
+          authorMember: person1MemberAccount,
           resolver: person3.publicKey,
           resolverMember: person3MemberAccount,
           daoTreasury: treasuryPDA,
           proposalTreasury: person1.publicKey,
+          proposalReceipt: getProposalReceiptPDA(proposalPDA),
+          rentDestination: person1.publicKey, // The author's wallet
+          proposalDeposit: getProposalDepositPDA(proposalPDA),
+          depositRecipient: person1.publicKey,
           systemProgram: SystemProgram.programId,
         })
         .signers([person3, creator, person1]).remainingAccounts([
@@ -641,36 +896,1424 @@ describe("prothesis - positive tests", () => {
       // Verify the treasury's balance decreased exactly by amount required
       expect(daoTreasuryBalanceAfter).to.equal(daoTreasuryBalanceBefore - proposalAccount.amountRequired.toNumber());
 
-      // Verify the proposal treasury's balance increased exactly by amount required
-      expect(proposalTreasuryBalanceAfter).to.equal(proposalTreasuryBalanceBefore + proposalAccount.amountRequired.toNumber());
+      // Verify the proposal treasury's balance increased exactly by amount required, plus the refunded rent since it is also the author's wallet
+      expect(proposalTreasuryBalanceAfter).to.equal(proposalTreasuryBalanceBefore + proposalAccount.amountRequired.toNumber() + proposalRent);
+
+      // Verify the outcome was recorded in the receipt
+      const receipt = await program.account.proposalReceipt.fetch(getProposalReceiptPDA(proposalPDA));
+      expect(receipt.proposal.toString()).to.equal(proposalPDA.toString());
+      expect(receipt.amountRequired.toString()).to.equal(proposalAccount.amountRequired.toString());
+      expect(receipt.abstentions.toString()).to.equal("1");
+      expect(receipt.status.executed).to.not.be.undefined;
+
+      // Verify the proposal account was closed
+      try {
+        await program.account.proposal.fetch(proposalPDA);
+        expect.fail("Proposal account should be closed");
+      } catch (error) {
+        expect(error.toString()).to.include("Account does not exist");
+      }
 
     });
   });
 
-  describe("Member Exit", () => {
-    it("Should allow a member to exit the DAO", async () => {
-      const membersCountBefore = (await program.account.daoConfig.fetch(daoConfigPDA)).membersCount;
+  describe("Funding Approvals", () => {
+    const fundingProposalTitle = "Funding Approval Proposal";
+    let fundingProposalPDA: PublicKey;
+
+    before(async () => {
+      fundingProposalPDA = await getNextProposalPDA();
 
       await program.methods
-        .exitDao()
+        .submitProposal(fundingProposalTitle, inlineContent, person2.publicKey, new anchor.BN(0.01 * LAMPORTS_PER_SOL), { standard: {} }, [])
         .accountsStrict({
           daoConfig: daoConfigPDA,
-          exitingMember: person3MemberAccount,
-          exiter: person3.publicKey,
-          treasury: treasuryPDA,
+          proposal: fundingProposalPDA,
+          proposalDeposit: getProposalDepositPDA(fundingProposalPDA),
+          author: person2.publicKey,
+          authorMember: person2MemberAccount,
           systemProgram: SystemProgram.programId,
         })
-        .signers([person3])
+        .signers([person2])
         .rpc();
 
-      // Verify member count was updated
-      const daoConfig = await program.account.daoConfig.fetch(daoConfigPDA);
-      expect(daoConfig.membersCount.toString()).to.equal(membersCountBefore.subn(1).toString());
+      await program.methods
+        .openVoting()
+        .accountsStrict({
+          author: person2.publicKey,
+          daoConfig: daoConfigPDA,
+          authorMember: person2MemberAccount,
+          proposal: fundingProposalPDA,
+        })
+        .signers([person2])
+        .rpc();
 
-      // Verify member account was closed
+      for (const [voter, voterMember] of [[creator, creatorMemberAccount], [person1, person1MemberAccount], [person2, person2MemberAccount]] as [Keypair, PublicKey][]) {
+        await program.methods
+          .voteOnProposal(1)
+          .accountsStrict({
+            daoConfig: daoConfigPDA,
+            proposal: fundingProposalPDA,
+            vote: getVotePDA(voterMember, fundingProposalPDA),
+            voter: voter.publicKey,
+            voterMember,
+            voterDeposit: null,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([voter])
+          .rpc();
+      }
+
+      await program.methods
+        .reviewProposal()
+        .accountsStrict({
+          daoConfig: daoConfigPDA,
+          proposal: fundingProposalPDA,
+          reviewer: person2.publicKey,
+          reviewerMember: person2MemberAccount,
+          systemProgram: SystemProgram.programId
+        })
+        .signers([person2])
+        .rpc();
+    });
+
+    it("Should let council members approve funding in separate transactions", async () => {
+      for (const [approver, approverMember] of [[creator, creatorMemberAccount], [person1, person1MemberAccount]] as [Keypair, PublicKey][]) {
+        await program.methods
+          .approveFunding()
+          .accountsStrict({
+            daoConfig: daoConfigPDA,
+            proposal: fundingProposalPDA,
+            approver: approver.publicKey,
+            approverMember,
+            fundingApproval: getFundingApprovalPDA(approverMember, fundingProposalPDA),
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([approver])
+          .rpc();
+      }
+
+      // Verify the approvals were recorded
+      const approval = await program.account.fundingApproval.fetch(getFundingApprovalPDA(person1MemberAccount, fundingProposalPDA));
+      expect(approval.approver.toString()).to.equal(person1MemberAccount.toString());
+
+      const proposal = await program.account.proposal.fetch(fundingProposalPDA);
+      expect(proposal.fundingApprovals.toString()).to.equal("2");
+    });
+
+    it("Should let a council member revoke their funding approval", async () => {
+      const fundingApprovalPDA = getFundingApprovalPDA(person1MemberAccount, fundingProposalPDA);
+
+      await program.methods
+        .revokeFundingApproval()
+        .accountsStrict({
+          daoConfig: daoConfigPDA,
+          proposal: fundingProposalPDA,
+          approver: person1.publicKey,
+          approverMember: person1MemberAccount,
+          fundingApproval: fundingApprovalPDA,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([person1])
+        .rpc();
+
+      // Verify the approval count was decremented
+      const proposal = await program.account.proposal.fetch(fundingProposalPDA);
+      expect(proposal.fundingApprovals.toString()).to.equal("1");
+
+      // Verify the approval account was closed
       try {
-        await program.account.member.fetch(person3MemberAccount);
-        expect.fail("Member account should be closed");
+        await program.account.fundingApproval.fetch(fundingApprovalPDA);
+        expect.fail("Funding approval account should be closed");
+      } catch (error) {
+        expect(error.toString()).to.include("Account does not exist");
+      }
+    });
+  });
+
+  describe("Vote Rent Reclamation", () => {
+    it("Should close votes on finalized proposals and role ops in one transaction", async () => {
+      const proposalVotePDA = getVotePDA(creatorMemberAccount, proposalPDA);
+      const roleOpVotePDA = getVotePDA(creatorMemberAccount, promotionRoleOpPDA);
+
+      const creatorBalanceBefore = await provider.connection.getBalance(creator.publicKey);
+      const voteRent = (await provider.connection.getBalance(proposalVotePDA)) + (await provider.connection.getBalance(roleOpVotePDA));
+
+      await program.methods
+        .closeVote()
+        .accountsStrict({
+          daoConfig: daoConfigPDA,
+          voter: creator.publicKey,
+          voterMember: creatorMemberAccount,
+          target: proposalPDA, // Resolved and closed
+          vote: proposalVotePDA,
+          rentPayer: creator.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .remainingAccounts([
+          { pubkey: promotionRoleOpPDA, isSigner: false, isWritable: false }, // Resolved and closed
+          { pubkey: roleOpVotePDA, isSigner: false, isWritable: true },
+          { pubkey: creator.publicKey, isSigner: false, isWritable: true }, // Paid the role op vote's rent
+        ])
+        .signers([creator])
+        .rpc();
+
+      // Verify the rent was refunded to the voter (the provider wallet pays the fees)
+      const creatorBalanceAfter = await provider.connection.getBalance(creator.publicKey);
+      expect(creatorBalanceAfter).to.equal(creatorBalanceBefore + voteRent);
+
+      // Verify both vote accounts were closed
+      for (const votePDA of [proposalVotePDA, roleOpVotePDA]) {
+        try {
+          await program.account.vote.fetch(votePDA);
+          expect.fail("Vote account should be closed");
+        } catch (error) {
+          expect(error.toString()).to.include("Account does not exist");
+        }
+      }
+    });
+  });
+
+  describe("Vote Delegation", () => {
+    const delegationProposalTitle = "Delegated Votes Proposal";
+
+    before(async () => {
+      delegationProposalPDA = await getNextProposalPDA();
+
+      await program.methods
+        .submitProposal(delegationProposalTitle, inlineContent, person1.publicKey, new anchor.BN(0.01 * LAMPORTS_PER_SOL), { standard: {} }, [])
+        .accountsStrict({
+          daoConfig: daoConfigPDA,
+          proposal: delegationProposalPDA,
+          proposalDeposit: getProposalDepositPDA(delegationProposalPDA),
+          author: person1.publicKey,
+          authorMember: person1MemberAccount,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([person1])
+        .rpc();
+
+      await program.methods
+        .openVoting()
+        .accountsStrict({
+          author: person1.publicKey,
+          daoConfig: daoConfigPDA,
+          authorMember: person1MemberAccount,
+          proposal: delegationProposalPDA,
+        })
+        .signers([person1])
+        .rpc();
+
+      await program.methods
+        .voteOnProposal(1)
+        .accountsStrict({
+          daoConfig: daoConfigPDA,
+          proposal: delegationProposalPDA,
+          vote: getVotePDA(person1MemberAccount, delegationProposalPDA),
+          voter: person1.publicKey,
+          voterMember: person1MemberAccount,
+          voterDeposit: null,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([person1])
+        .rpc();
+    });
+
+    it("Should delegate votes to another member", async () => {
+      for (const [delegator, delegatorMember] of [[person2, person2MemberAccount], [person3, person3MemberAccount]] as const) {
+        await program.methods
+          .delegateVote()
+          .accountsStrict({
+            daoConfig: daoConfigPDA,
+            delegator: delegator.publicKey,
+            delegatorMember,
+            delegateMember: person1MemberAccount,
+            delegation: getDelegationPDA(delegatorMember, daoConfigPDA),
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([delegator])
+          .rpc();
+      }
+
+      // Verify the delegation was recorded
+      const delegation = await program.account.delegation.fetch(getDelegationPDA(person2MemberAccount, daoConfigPDA));
+      expect(delegation.delegator.toString()).to.equal(person2MemberAccount.toString());
+      expect(delegation.delegate.toString()).to.equal(person1MemberAccount.toString());
+    });
+
+    it("Should cast the delegate's vote for the delegators", async () => {
+      await program.methods
+        .castDelegatedVotes()
+        .accountsStrict({
+          daoConfig: daoConfigPDA,
+          proposal: delegationProposalPDA,
+          delegate: person1.publicKey,
+          delegateMember: person1MemberAccount,
+          delegateVote: getVotePDA(person1MemberAccount, delegationProposalPDA),
+          systemProgram: SystemProgram.programId,
+        })
+        .remainingAccounts([person2MemberAccount, person3MemberAccount].flatMap((delegatorMember) => [
+          { pubkey: delegatorMember, isSigner: false, isWritable: true },
+          { pubkey: getDelegationPDA(delegatorMember, daoConfigPDA), isSigner: false, isWritable: false },
+          { pubkey: getVotePDA(delegatorMember, delegationProposalPDA), isSigner: false, isWritable: true },
+        ]))
+        .signers([person1])
+        .rpc();
+
+      // Verify the delegators' votes were cast by the delegate
+      const vote = await program.account.vote.fetch(getVotePDA(person2MemberAccount, delegationProposalPDA));
+      expect("upvote" in vote.voteType).to.be.true;
+      expect(vote.castBy.toString()).to.equal(person1MemberAccount.toString());
+
+      // Verify the delegated votes were counted
+      const proposal = await program.account.proposal.fetch(delegationProposalPDA);
+      expect(proposal.upvotes.toString()).to.equal("3");
+    });
+
+    it("Should let a delegator override the delegate's vote", async () => {
+      const proposalVotePDA = getVotePDA(person2MemberAccount, delegationProposalPDA);
+
+      await program.methods
+        .changeVoteOnProposal(0)
+        .accountsStrict({
+          daoConfig: daoConfigPDA,
+          proposal: delegationProposalPDA,
+          vote: proposalVotePDA,
+          voter: person2.publicKey,
+          voterMember: person2MemberAccount,
+        })
+        .signers([person2])
+        .rpc();
+
+      // Verify the vote is now the delegator's own
+      const vote = await program.account.vote.fetch(proposalVotePDA);
+      expect("downvote" in vote.voteType).to.be.true;
+      expect(vote.castBy).to.be.null;
+
+      // Verify the tally was updated
+      const proposal = await program.account.proposal.fetch(delegationProposalPDA);
+      expect(proposal.upvotes.toString()).to.equal("2");
+      expect(proposal.downvotes.toString()).to.equal("1");
+    });
+
+    it("Should revoke a delegation", async () => {
+      const delegationPDA = getDelegationPDA(person2MemberAccount, daoConfigPDA);
+
+      await program.methods
+        .revokeDelegation()
+        .accountsStrict({
+          daoConfig: daoConfigPDA,
+          delegator: person2.publicKey,
+          delegatorMember: person2MemberAccount,
+          delegation: delegationPDA,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([person2])
+        .rpc();
+
+      // Verify the delegation account was closed
+      try {
+        await program.account.delegation.fetch(delegationPDA);
+        expect.fail("Delegation account should be closed");
+      } catch (error) {
+        expect(error.toString()).to.include("Account does not exist");
+      }
+    });
+  });
+
+  describe("Quadratic Voting", () => {
+    let quadraticProposalPDA: PublicKey;
+    let round: anchor.BN;
+
+    it("Should submit a quadratic proposal", async () => {
+      quadraticProposalPDA = await getNextProposalPDA();
+
+      await program.methods
+        .submitProposal("Quadratic Proposal", inlineContent, person1.publicKey, new anchor.BN(0.01 * LAMPORTS_PER_SOL), { quadratic: {} }, [])
+        .accountsStrict({
+          daoConfig: daoConfigPDA,
+          proposal: quadraticProposalPDA,
+          proposalDeposit: getProposalDepositPDA(quadraticProposalPDA),
+          author: person1.publicKey,
+          authorMember: person1MemberAccount,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([person1])
+        .rpc();
+
+      await program.methods
+        .openVoting()
+        .accountsStrict({
+          author: person1.publicKey,
+          daoConfig: daoConfigPDA,
+          authorMember: person1MemberAccount,
+          proposal: quadraticProposalPDA,
+        })
+        .signers([person1])
+        .rpc();
+
+      // Verify the proposal was assigned the current voice credit round
+      const proposal = await program.account.proposal.fetch(quadraticProposalPDA);
+      expect(proposal.kind.quadratic).to.not.be.undefined;
+      expect(proposal.round.toString()).to.equal(proposal.votingOpenedAt.div(voiceCreditRoundDuration).toString());
+      round = proposal.round;
+    });
+
+    it("Should spend the square of the votes cast in voice credits", async () => {
+      for (const [voter, voterMember, vote, votes] of [[person1, person1MemberAccount, 1, 3], [person2, person2MemberAccount, 0, 2]] as const) {
+        await program.methods
+          .quadraticVote(vote, new anchor.BN(votes))
+          .accountsStrict({
+            daoConfig: daoConfigPDA,
+            proposal: quadraticProposalPDA,
+            vote: getVotePDA(voterMember, quadraticProposalPDA),
+            voter: voter.publicKey,
+            voterMember,
+            voiceCredits: getVoiceCreditsPDA(voterMember, daoConfigPDA, round),
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([voter])
+          .rpc();
+      }
+
+      // Verify the tally sums the vote weights
+      const proposal = await program.account.proposal.fetch(quadraticProposalPDA);
+      expect(proposal.upvotes.toString()).to.equal("3");
+      expect(proposal.downvotes.toString()).to.equal("2");
+      expect(proposal.votersCount.toString()).to.equal("2");
+
+      // Verify 3 votes cost 9 voice credits
+      const voiceCredits = await program.account.voiceCreditLedger.fetch(getVoiceCreditsPDA(person1MemberAccount, daoConfigPDA, round));
+      expect(voiceCredits.creditsSpent.toString()).to.equal("9");
+    });
+
+    it("Should refund the voice credits of a retracted quadratic vote", async () => {
+      await program.methods
+        .retractVoteOnProposal()
+        .accountsStrict({
+          daoConfig: daoConfigPDA,
+          proposal: quadraticProposalPDA,
+          vote: getVotePDA(person1MemberAccount, quadraticProposalPDA),
+          rentPayer: person1.publicKey,
+          voter: person1.publicKey,
+          voterMember: person1MemberAccount,
+          voiceCredits: getVoiceCreditsPDA(person1MemberAccount, daoConfigPDA, round),
+          voterDeposit: null,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([person1])
+        .rpc();
+
+      // Verify the credits were refunded and the tally updated
+      const voiceCredits = await program.account.voiceCreditLedger.fetch(getVoiceCreditsPDA(person1MemberAccount, daoConfigPDA, round));
+      expect(voiceCredits.creditsSpent.toString()).to.equal("0");
+
+      const proposal = await program.account.proposal.fetch(quadraticProposalPDA);
+      expect(proposal.upvotes.toString()).to.equal("0");
+      expect(proposal.votersCount.toString()).to.equal("1");
+    });
+
+    it("Should refund the voice credits of votes on a cancelled quadratic proposal", async () => {
+      await program.methods
+        .cancelProposal()
+        .accountsStrict({
+          author: person1.publicKey,
+          daoConfig: daoConfigPDA,
+          authorMember: person1MemberAccount,
+          proposal: quadraticProposalPDA,
+        })
+        .signers([person1])
+        .rpc();
+
+      await program.methods
+        .retractVoteOnProposal()
+        .accountsStrict({
+          daoConfig: daoConfigPDA,
+          proposal: quadraticProposalPDA,
+          vote: getVotePDA(person2MemberAccount, quadraticProposalPDA),
+          rentPayer: person2.publicKey,
+          voter: person2.publicKey,
+          voterMember: person2MemberAccount,
+          voiceCredits: getVoiceCreditsPDA(person2MemberAccount, daoConfigPDA, round),
+          voterDeposit: null,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([person2])
+        .rpc();
+
+      // Verify the credits spent on the cancelled proposal were refunded
+      const voiceCredits = await program.account.voiceCreditLedger.fetch(getVoiceCreditsPDA(person2MemberAccount, daoConfigPDA, round));
+      expect(voiceCredits.creditsSpent.toString()).to.equal("0");
+    });
+  });
+
+  describe("Conviction Voting", () => {
+    let convictionProposalPDA: PublicKey;
+
+    it("Should accrue conviction from staked support and fund the proposal once it passes", async () => {
+      convictionProposalPDA = await getNextProposalPDA();
+
+      await program.methods
+        .submitProposal("Conviction Proposal", inlineContent, person2.publicKey, new anchor.BN(0.01 * LAMPORTS_PER_SOL), { conviction: {} }, [])
+        .accountsStrict({
+          daoConfig: daoConfigPDA,
+          proposal: convictionProposalPDA,
+          proposalDeposit: getProposalDepositPDA(convictionProposalPDA),
+          author: person2.publicKey,
+          authorMember: person2MemberAccount,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([person2])
+        .rpc();
+
+      await program.methods
+        .openVoting()
+        .accountsStrict({
+          author: person2.publicKey,
+          daoConfig: daoConfigPDA,
+          authorMember: person2MemberAccount,
+          proposal: convictionProposalPDA,
+        })
+        .signers([person2])
+        .rpc();
+
+      for (const [voter, voterMember] of [[creator, creatorMemberAccount], [person2, person2MemberAccount]] as const) {
+        await program.methods
+          .voteOnProposal(1)
+          .accountsStrict({
+            daoConfig: daoConfigPDA,
+            proposal: convictionProposalPDA,
+            vote: getVotePDA(voterMember, convictionProposalPDA),
+            voter: voter.publicKey,
+            voterMember,
+            voterDeposit: null,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([voter])
+          .rpc();
+      }
+
+      // Let the conviction accrue for more than a half-life
+      await new Promise(resolve => setTimeout(resolve, 3000));
+
+      const proposalTreasuryBalanceBefore = await provider.connection.getBalance(person2.publicKey);
+
+      await program.methods
+        .updateConviction()
+        .accountsStrict({
+          daoConfig: daoConfigPDA,
+          proposal: convictionProposalPDA,
+          cranker: creator.publicKey, // Anybody can crank the conviction
+          daoTreasury: treasuryPDA,
+          proposalTreasury: person2.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([creator])
+        .rpc();
+
+      // Verify the proposal passed and was funded
+      const proposal = await program.account.proposal.fetch(convictionProposalPDA);
+      expect(proposal.conviction.toNumber()).to.be.greaterThan(0);
+      expect(proposal.status.executed).to.not.be.undefined;
+
+      const proposalTreasuryBalanceAfter = await provider.connection.getBalance(person2.publicKey);
+      expect(proposalTreasuryBalanceAfter).to.equal(proposalTreasuryBalanceBefore + 0.01 * LAMPORTS_PER_SOL);
+    });
+  });
+
+  describe("Token-Weighted Voting", () => {
+    const weightedDaoId = new anchor.BN(Math.floor(Math.random() * 10_000_000_000));
+    let weightedDaoConfigPDA: PublicKey;
+    let weightedCreatorMember: PublicKey;
+    let weightedPerson1Member: PublicKey;
+    let weightedProposalPDA: PublicKey;
+    let governanceMint: PublicKey;
+    let creatorTokenAccount: PublicKey;
+    let person1TokenAccount: PublicKey;
+
+    before(async () => {
+      weightedDaoConfigPDA = getDAOConfigPDA(weightedDaoId);
+      weightedCreatorMember = getMemberAccount(creator.publicKey, weightedDaoConfigPDA);
+      weightedPerson1Member = getMemberAccount(person1.publicKey, weightedDaoConfigPDA);
+
+      ({ mint: governanceMint, tokenAccounts: [creatorTokenAccount, person1TokenAccount] } = await createGovernanceMint(provider, creator, [
+        { owner: creator.publicKey, amount: 100 },
+        { owner: person1.publicKey, amount: 50 },
+      ]));
+    });
+
+    it("Should initialize a token-weighted DAO", async () => {
+      await program.methods
+        .initializeDao(weightedDaoId, { consensusPct, quorumPct, consensusLifetime: new anchor.BN(60), minMultisigSigners, proposalRentDestination, voiceCreditsPerRound, voiceCreditRoundDuration, convictionHalfLife, convictionMinPct, revealLifetime, vetoPeriod, vetoPct, executionDelay, minSponsors, proposalDeposit, forfeitDismissedDeposits, maxOpenProposalsPerMember, proposalCooldown })
+        .accountsStrict({
+          daoConfig: weightedDaoConfigPDA,
+          treasury: getDAOTreasury(weightedDaoConfigPDA),
+          creator: creator.publicKey,
+          member: weightedCreatorMember,
+          governanceMint,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([creator])
+        .rpc();
+
+      await program.methods
+        .addMember()
+        .accountsStrict({
+          daoConfig: weightedDaoConfigPDA,
+          aspirant: person1.publicKey,
+          newMember: weightedPerson1Member,
+          councilMember: weightedCreatorMember,
+          councilSigner: creator.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([creator, person1])
+        .rpc();
+
+      // Verify the governance mint was recorded
+      const daoConfig = await program.account.daoConfig.fetch(weightedDaoConfigPDA);
+      expect(daoConfig.governanceMint.toString()).to.equal(governanceMint.toString());
+    });
+
+    it("Should deposit governance tokens", async () => {
+      for (const [depositor, depositorMember, tokenAccount, amount] of [
+        [creator, weightedCreatorMember, creatorTokenAccount, 100],
+        [person1, weightedPerson1Member, person1TokenAccount, 50],
+      ] as const) {
+        await program.methods
+          .depositGovernanceTokens(new anchor.BN(amount))
+          .accountsStrict({
+            daoConfig: weightedDaoConfigPDA,
+            depositor: depositor.publicKey,
+            depositorMember,
+            governanceMint,
+            depositorTokenAccount: tokenAccount,
+            governanceVault: getGovernanceVaultPDA(weightedDaoConfigPDA),
+            deposit: getGovernanceDepositPDA(depositor.publicKey, weightedDaoConfigPDA),
+            tokenProgram: TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([depositor])
+          .rpc();
+      }
+
+      // Verify the deposits were recorded
+      const deposit = await program.account.governanceDeposit.fetch(getGovernanceDepositPDA(person1.publicKey, weightedDaoConfigPDA));
+      expect(deposit.amount.toString()).to.equal("50");
+
+      const daoConfig = await program.account.daoConfig.fetch(weightedDaoConfigPDA);
+      expect(daoConfig.totalGovernanceTokens.toString()).to.equal("150");
+    });
+
+    it("Should weight proposal votes by the deposited tokens", async () => {
+      weightedProposalPDA = getProposalPDA(new anchor.BN(0), weightedDaoConfigPDA);
+
+      await program.methods
+        .submitProposal("Token-Weighted Proposal", inlineContent, person1.publicKey, new anchor.BN(0), { standard: {} }, [])
+        .accountsStrict({
+          daoConfig: weightedDaoConfigPDA,
+          proposal: weightedProposalPDA,
+          proposalDeposit: getProposalDepositPDA(weightedProposalPDA),
+          author: person1.publicKey,
+          authorMember: weightedPerson1Member,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([person1])
+        .rpc();
+
+      await program.methods
+        .openVoting()
+        .accountsStrict({
+          author: person1.publicKey,
+          daoConfig: weightedDaoConfigPDA,
+          authorMember: weightedPerson1Member,
+          proposal: weightedProposalPDA,
+        })
+        .signers([person1])
+        .rpc();
+
+      for (const [voter, voterMember, vote] of [[creator, weightedCreatorMember, 1], [person1, weightedPerson1Member, 0]] as const) {
+        await program.methods
+          .voteOnProposal(vote)
+          .accountsStrict({
+            daoConfig: weightedDaoConfigPDA,
+            proposal: weightedProposalPDA,
+            vote: getVotePDA(voterMember, weightedProposalPDA),
+            voter: voter.publicKey,
+            voterMember,
+            voterDeposit: getGovernanceDepositPDA(voter.publicKey, weightedDaoConfigPDA),
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([voter])
+          .rpc();
+      }
+
+      // Verify the votes were weighted against the deposited tokens
+      const proposal = await program.account.proposal.fetch(weightedProposalPDA);
+      expect(proposal.electorate.toString()).to.equal("150");
+      expect(proposal.upvotes.toString()).to.equal("100");
+      expect(proposal.downvotes.toString()).to.equal("50");
+
+      const vote = await program.account.vote.fetch(getVotePDA(weightedCreatorMember, weightedProposalPDA));
+      expect(vote.weight.toString()).to.equal("100");
+
+      // Verify the tokens backing the vote are locked until voting ends
+      const deposit = await program.account.governanceDeposit.fetch(getGovernanceDepositPDA(creator.publicKey, weightedDaoConfigPDA));
+      expect(deposit.lockedUntil.toNumber()).to.equal(proposal.votingOpenedAt.toNumber() + 60);
+    });
+
+    it("Should only credit the tokens the vault received from a mint with a transfer fee", async () => {
+      const feeDaoId = new anchor.BN(Math.floor(Math.random() * 10_000_000_000));
+      const feeDaoConfigPDA = getDAOConfigPDA(feeDaoId);
+      const feeCreatorMember = getMemberAccount(creator.publicKey, feeDaoConfigPDA);
+
+      // A 1% fee is withheld from every transfer
+      const { mint: feeMint, tokenAccounts: [feeTokenAccount] } = await createTransferFeeGovernanceMint(provider, creator, 100, [
+        { owner: creator.publicKey, amount: 1000 },
+      ]);
+
+      await program.methods
+        .initializeDao(feeDaoId, { consensusPct, quorumPct, consensusLifetime, minMultisigSigners, proposalRentDestination, voiceCreditsPerRound, voiceCreditRoundDuration, convictionHalfLife, convictionMinPct, revealLifetime, vetoPeriod, vetoPct, executionDelay, minSponsors, proposalDeposit, forfeitDismissedDeposits, maxOpenProposalsPerMember, proposalCooldown })
+        .accountsStrict({
+          daoConfig: feeDaoConfigPDA,
+          treasury: getDAOTreasury(feeDaoConfigPDA),
+          creator: creator.publicKey,
+          member: feeCreatorMember,
+          governanceMint: feeMint,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([creator])
+        .rpc();
+
+      await program.methods
+        .depositGovernanceTokens(new anchor.BN(1000))
+        .accountsStrict({
+          daoConfig: feeDaoConfigPDA,
+          depositor: creator.publicKey,
+          depositorMember: feeCreatorMember,
+          governanceMint: feeMint,
+          depositorTokenAccount: feeTokenAccount,
+          governanceVault: getGovernanceVaultPDA(feeDaoConfigPDA),
+          deposit: getGovernanceDepositPDA(creator.publicKey, feeDaoConfigPDA),
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([creator])
+        .rpc();
+
+      // Verify only the 990 tokens left after the fee were credited, matching the vault's balance
+      const deposit = await program.account.governanceDeposit.fetch(getGovernanceDepositPDA(creator.publicKey, feeDaoConfigPDA));
+      expect(deposit.amount.toString()).to.equal("990");
+
+      const daoConfig = await program.account.daoConfig.fetch(feeDaoConfigPDA);
+      expect(daoConfig.totalGovernanceTokens.toString()).to.equal("990");
+
+      const vault = await getAccount(provider.connection, getGovernanceVaultPDA(feeDaoConfigPDA), undefined, TOKEN_2022_PROGRAM_ID);
+      expect(vault.amount.toString()).to.equal("990");
+    });
+  });
+
+  describe("Commit-Reveal Voting", () => {
+    const secretDaoId = new anchor.BN(Math.floor(Math.random() * 10_000_000_000));
+    const secretConsensusLifetime = new anchor.BN(3); // 3 seconds
+    let secretDaoConfigPDA: PublicKey;
+    let secretCreatorMember: PublicKey;
+    let secretProposalPDA: PublicKey;
+    const salt = Buffer.alloc(32, 7);
+
+    before(async () => {
+      secretDaoConfigPDA = getDAOConfigPDA(secretDaoId);
+      secretCreatorMember = getMemberAccount(creator.publicKey, secretDaoConfigPDA);
+      secretProposalPDA = getProposalPDA(new anchor.BN(0), secretDaoConfigPDA);
+
+      await program.methods
+        .initializeDao(secretDaoId, { consensusPct, quorumPct, consensusLifetime: secretConsensusLifetime, minMultisigSigners, proposalRentDestination, voiceCreditsPerRound, voiceCreditRoundDuration, convictionHalfLife, convictionMinPct, revealLifetime: new anchor.BN(60), vetoPeriod, vetoPct, executionDelay, minSponsors, proposalDeposit, forfeitDismissedDeposits, maxOpenProposalsPerMember, proposalCooldown })
+        .accountsStrict({
+          daoConfig: secretDaoConfigPDA,
+          treasury: getDAOTreasury(secretDaoConfigPDA),
+          creator: creator.publicKey,
+          member: secretCreatorMember,
+          governanceMint: null,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([creator])
+        .rpc();
+
+      await program.methods
+        .submitProposal("Secret Ballot Proposal", inlineContent, creator.publicKey, new anchor.BN(0), { standard: {} }, [])
+        .accountsStrict({
+          daoConfig: secretDaoConfigPDA,
+          proposal: secretProposalPDA,
+          proposalDeposit: getProposalDepositPDA(secretProposalPDA),
+          author: creator.publicKey,
+          authorMember: secretCreatorMember,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([creator])
+        .rpc();
+
+      await program.methods
+        .openVoting()
+        .accountsStrict({
+          author: creator.publicKey,
+          daoConfig: secretDaoConfigPDA,
+          authorMember: secretCreatorMember,
+          proposal: secretProposalPDA,
+        })
+        .signers([creator])
+        .rpc();
+    });
+
+    it("Should commit a hidden vote and count it once revealed", async () => {
+      const commitmentPDA = getVoteCommitmentPDA(secretCreatorMember, secretProposalPDA);
+
+      await program.methods
+        .commitVote(hashVoteCommitment(1, salt, secretCreatorMember, secretProposalPDA))
+        .accountsStrict({
+          voter: creator.publicKey,
+          daoConfig: secretDaoConfigPDA,
+          proposal: secretProposalPDA,
+          voterMember: secretCreatorMember,
+          commitment: commitmentPDA,
+          voterDeposit: null,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([creator])
+        .rpc();
+
+      // Verify nothing is tallied while the vote is hidden
+      let proposal = await program.account.proposal.fetch(secretProposalPDA);
+      expect(proposal.upvotes.toNumber()).to.equal(0);
+      expect(proposal.votersCount.toNumber()).to.equal(0);
+
+      // Wait for the commit phase to end
+      await new Promise(resolve => setTimeout(resolve, (secretConsensusLifetime.toNumber() + 2) * 1000));
+
+      await program.methods
+        .revealVote(1, Array.from(salt))
+        .accountsStrict({
+          voter: creator.publicKey,
+          daoConfig: secretDaoConfigPDA,
+          proposal: secretProposalPDA,
+          voterMember: secretCreatorMember,
+          commitment: commitmentPDA,
+          vote: getVotePDA(secretCreatorMember, secretProposalPDA),
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([creator])
+        .rpc();
+
+      // Verify the revealed vote was counted and the commitment closed
+      proposal = await program.account.proposal.fetch(secretProposalPDA);
+      expect(proposal.upvotes.toNumber()).to.equal(1);
+      expect(proposal.votersCount.toNumber()).to.equal(1);
+
+      const commitmentAccount = await provider.connection.getAccountInfo(commitmentPDA);
+      expect(commitmentAccount).to.be.null;
+    });
+  });
+
+  describe("Choice Proposals", () => {
+    it("Should pick the winner of a ranked-choice proposal by instant-runoff", async () => {
+      const rankedProposalPDA = await getNextProposalPDA();
+      const options = [
+        { label: "Vendor A", treasury: person1.publicKey, amount: new anchor.BN(0.01 * LAMPORTS_PER_SOL) },
+        { label: "Vendor B", treasury: person2.publicKey, amount: new anchor.BN(0.02 * LAMPORTS_PER_SOL) },
+        { label: "Vendor C", treasury: person3.publicKey, amount: new anchor.BN(0.03 * LAMPORTS_PER_SOL) },
+      ];
+
+      await program.methods
+        .submitProposal("Ranked-Choice Proposal", inlineContent, creator.publicKey, new anchor.BN(0), { rankedChoice: {} }, options)
+        .accountsStrict({
+          daoConfig: daoConfigPDA,
+          proposal: rankedProposalPDA,
+          proposalDeposit: getProposalDepositPDA(rankedProposalPDA),
+          author: creator.publicKey,
+          authorMember: creatorMemberAccount,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([creator])
+        .rpc();
+
+      await program.methods
+        .openVoting()
+        .accountsStrict({
+          author: creator.publicKey,
+          daoConfig: daoConfigPDA,
+          authorMember: creatorMemberAccount,
+          proposal: rankedProposalPDA,
+        })
+        .signers([creator])
+        .rpc();
+
+      const ballots = [
+        [creator, creatorMemberAccount, [0, 1]],
+        [person1, person1MemberAccount, [1]],
+        [person2, person2MemberAccount, [2, 1]],
+        [person3, person3MemberAccount, [1, 2]],
+      ] as const;
+      for (const [voter, voterMember, choices] of ballots) {
+        await program.methods
+          .voteOnOptions(Buffer.from(choices))
+          .accountsStrict({
+            voter: voter.publicKey,
+            daoConfig: daoConfigPDA,
+            proposal: rankedProposalPDA,
+            voterMember,
+            ballot: getBallotPDA(voterMember, rankedProposalPDA),
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([voter])
+          .rpc();
+      }
+
+      // Verify the first choices were tallied
+      let proposal = await program.account.proposal.fetch(rankedProposalPDA);
+      expect(proposal.options.map(option => option.votes.toNumber())).to.deep.equal([1, 2, 1]);
+
+      // Wait for the voting period to end
+      await new Promise(resolve => setTimeout(resolve, (consensusLifetime.toNumber() + 2) * 1000));
+
+      // Count the ballots over two reviews, as a large electorate would have to
+      for (const batch of [ballots.slice(0, 2), ballots.slice(2)]) {
+        await program.methods
+          .reviewProposal()
+          .accountsStrict({
+            daoConfig: daoConfigPDA,
+            proposal: rankedProposalPDA,
+            reviewer: creator.publicKey,
+            reviewerMember: creatorMemberAccount,
+            systemProgram: SystemProgram.programId
+          })
+          .remainingAccounts(batch.map(([, voterMember]) => ({ pubkey: getBallotPDA(voterMember, rankedProposalPDA), isSigner: false, isWritable: true })))
+          .signers([creator])
+          .rpc();
+
+        if (batch[0] === ballots[0]) {
+          // Vendor B leads the first preferences with 2 of 4 votes, short of consensus, so Vendor C
+          // was eliminated and the second round still waits for the other half of the ballots
+          proposal = await program.account.proposal.fetch(rankedProposalPDA);
+          expect(proposal.status.pending).to.not.be.undefined;
+          expect(proposal.runoffRound).to.equal(1);
+          expect(proposal.runoffBallots.toNumber()).to.equal(2);
+          expect(proposal.options[2].eliminated).to.be.true;
+        }
+      }
+
+      // Vendor C's ballot moves to Vendor B, which wins with 3 of 4 votes
+      proposal = await program.account.proposal.fetch(rankedProposalPDA);
+      expect(proposal.status.approved).to.not.be.undefined;
+      expect(proposal.winningOption).to.equal(1);
+      expect(proposal.treasury.toString()).to.equal(person2.publicKey.toString());
+      expect(proposal.amountRequired.toNumber()).to.equal(0.02 * LAMPORTS_PER_SOL);
+    });
+  });
+
+  describe("Council Veto", () => {
+    const vetoDaoId = new anchor.BN(Math.floor(Math.random() * 10_000_000_000));
+    let vetoDaoConfigPDA: PublicKey;
+    let vetoCreatorMember: PublicKey;
+    let vetoProposalPDA: PublicKey;
+
+    before(async () => {
+      vetoDaoConfigPDA = getDAOConfigPDA(vetoDaoId);
+      vetoCreatorMember = getMemberAccount(creator.publicKey, vetoDaoConfigPDA);
+      vetoProposalPDA = getProposalPDA(new anchor.BN(0), vetoDaoConfigPDA);
+
+      await program.methods
+        .initializeDao(vetoDaoId, { consensusPct, quorumPct, consensusLifetime, minMultisigSigners, proposalRentDestination, voiceCreditsPerRound, voiceCreditRoundDuration, convictionHalfLife, convictionMinPct, revealLifetime, vetoPeriod: new anchor.BN(60), vetoPct, executionDelay, minSponsors, proposalDeposit, forfeitDismissedDeposits, maxOpenProposalsPerMember, proposalCooldown })
+        .accountsStrict({
+          daoConfig: vetoDaoConfigPDA,
+          treasury: getDAOTreasury(vetoDaoConfigPDA),
+          creator: creator.publicKey,
+          member: vetoCreatorMember,
+          governanceMint: null,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([creator])
+        .rpc();
+
+      await program.methods
+        .submitProposal("Vetoable Proposal", inlineContent, creator.publicKey, new anchor.BN(0), { standard: {} }, [])
+        .accountsStrict({
+          daoConfig: vetoDaoConfigPDA,
+          proposal: vetoProposalPDA,
+          proposalDeposit: getProposalDepositPDA(vetoProposalPDA),
+          author: creator.publicKey,
+          authorMember: vetoCreatorMember,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([creator])
+        .rpc();
+
+      await program.methods
+        .openVoting()
+        .accountsStrict({
+          author: creator.publicKey,
+          daoConfig: vetoDaoConfigPDA,
+          authorMember: vetoCreatorMember,
+          proposal: vetoProposalPDA,
+        })
+        .signers([creator])
+        .rpc();
+
+      await program.methods
+        .voteOnProposal(1)
+        .accountsStrict({
+          daoConfig: vetoDaoConfigPDA,
+          proposal: vetoProposalPDA,
+          vote: getVotePDA(vetoCreatorMember, vetoProposalPDA),
+          voter: creator.publicKey,
+          voterMember: vetoCreatorMember,
+          voterDeposit: null,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([creator])
+        .rpc();
+
+      await program.methods
+        .reviewProposal()
+        .accountsStrict({
+          daoConfig: vetoDaoConfigPDA,
+          proposal: vetoProposalPDA,
+          reviewer: creator.publicKey,
+          reviewerMember: vetoCreatorMember,
+          systemProgram: SystemProgram.programId
+        })
+        .signers([creator])
+        .rpc();
+    });
+
+    it("Should let the council veto an approved proposal during its veto period", async () => {
+      const vetoPDA = getVetoPDA(vetoCreatorMember, vetoProposalPDA);
+
+      await program.methods
+        .vetoProposal("The treasury is needed elsewhere")
+        .accountsStrict({
+          vetoer: creator.publicKey,
+          daoConfig: vetoDaoConfigPDA,
+          councilMember: vetoCreatorMember,
+          proposal: vetoProposalPDA,
+          veto: vetoPDA,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([creator])
+        .rpc();
+
+      // Verify the sole council member's veto blocked the proposal
+      const proposal = await program.account.proposal.fetch(vetoProposalPDA);
+      expect(proposal.vetoes.toNumber()).to.equal(1);
+      expect(proposal.councilSize.toNumber()).to.equal(1);
+      expect(proposal.status.vetoed).to.not.be.undefined;
+
+      const veto = await program.account.veto.fetch(vetoPDA);
+      expect(veto.reason).to.equal("The treasury is needed elsewhere");
+    });
+  });
+
+  describe("Proposal Cancellation", () => {
+    it("Should let the author cancel a pending proposal and voters close their votes", async () => {
+      const cancelledProposalPDA = await getNextProposalPDA();
+      const person1VotePDA = getVotePDA(person1MemberAccount, cancelledProposalPDA);
+
+      await program.methods
+        .submitProposal("Mistaken Proposal", inlineContent, creator.publicKey, new anchor.BN(0), { standard: {} }, [])
+        .accountsStrict({
+          daoConfig: daoConfigPDA,
+          proposal: cancelledProposalPDA,
+          proposalDeposit: getProposalDepositPDA(cancelledProposalPDA),
+          author: creator.publicKey,
+          authorMember: creatorMemberAccount,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([creator])
+        .rpc();
+
+      await program.methods
+        .openVoting()
+        .accountsStrict({
+          author: creator.publicKey,
+          daoConfig: daoConfigPDA,
+          authorMember: creatorMemberAccount,
+          proposal: cancelledProposalPDA,
+        })
+        .signers([creator])
+        .rpc();
+
+      await program.methods
+        .voteOnProposal(1)
+        .accountsStrict({
+          daoConfig: daoConfigPDA,
+          proposal: cancelledProposalPDA,
+          vote: person1VotePDA,
+          voter: person1.publicKey,
+          voterMember: person1MemberAccount,
+          voterDeposit: null,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([person1])
+        .rpc();
+
+      await program.methods
+        .cancelProposal()
+        .accountsStrict({
+          author: creator.publicKey,
+          daoConfig: daoConfigPDA,
+          authorMember: creatorMemberAccount,
+          proposal: cancelledProposalPDA,
+        })
+        .signers([creator])
+        .rpc();
+
+      // Verify the proposal was cancelled without waiting for its lifetime
+      const proposal = await program.account.proposal.fetch(cancelledProposalPDA);
+      expect(proposal.status.cancelled).to.not.be.undefined;
+
+      await program.methods
+        .closeVote()
+        .accountsStrict({
+          daoConfig: daoConfigPDA,
+          voter: person1.publicKey,
+          voterMember: person1MemberAccount,
+          target: cancelledProposalPDA,
+          vote: person1VotePDA,
+          rentPayer: person1.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([person1])
+        .rpc();
+
+      // Verify the vote on the cancelled proposal was closed
+      const voteAccount = await provider.connection.getAccountInfo(person1VotePDA);
+      expect(voteAccount).to.be.null;
+    });
+  });
+
+  describe("Proposal Sponsorship", () => {
+    it("Should let members sponsor a draft proposal and withdraw their sponsorship", async () => {
+      const sponsoredProposalPDA = await getNextProposalPDA();
+      const person1SponsorshipPDA = getSponsorshipPDA(person1MemberAccount, sponsoredProposalPDA);
+      const person2SponsorshipPDA = getSponsorshipPDA(person2MemberAccount, sponsoredProposalPDA);
+
+      await program.methods
+        .submitProposal("Sponsored Proposal", inlineContent, creator.publicKey, new anchor.BN(0), { standard: {} }, [])
+        .accountsStrict({
+          daoConfig: daoConfigPDA,
+          proposal: sponsoredProposalPDA,
+          proposalDeposit: getProposalDepositPDA(sponsoredProposalPDA),
+          author: creator.publicKey,
+          authorMember: creatorMemberAccount,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([creator])
+        .rpc();
+
+      for (const [sponsor, sponsorMember, sponsorship] of [
+        [person1, person1MemberAccount, person1SponsorshipPDA],
+        [person2, person2MemberAccount, person2SponsorshipPDA],
+      ] as [Keypair, PublicKey, PublicKey][]) {
+        await program.methods
+          .sponsorProposal()
+          .accountsStrict({
+            sponsor: sponsor.publicKey,
+            daoConfig: daoConfigPDA,
+            sponsorMember,
+            proposal: sponsoredProposalPDA,
+            sponsorship,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([sponsor])
+          .rpc();
+      }
+
+      await program.methods
+        .withdrawSponsorship()
+        .accountsStrict({
+          sponsor: person2.publicKey,
+          daoConfig: daoConfigPDA,
+          sponsorMember: person2MemberAccount,
+          proposal: sponsoredProposalPDA,
+          sponsorship: person2SponsorshipPDA,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([person2])
+        .rpc();
+
+      // Verify only the remaining sponsorship is counted
+      const proposal = await program.account.proposal.fetch(sponsoredProposalPDA);
+      expect(proposal.sponsors.toNumber()).to.equal(1);
+
+      const sponsorship = await program.account.sponsorship.fetch(person1SponsorshipPDA);
+      expect(sponsorship.sponsor.toString()).to.equal(person1MemberAccount.toString());
+
+      const withdrawnSponsorship = await provider.connection.getAccountInfo(person2SponsorshipPDA);
+      expect(withdrawnSponsorship).to.be.null;
+    });
+  });
+
+  describe("Proposal Deposits", () => {
+    const depositDaoId = new anchor.BN(Math.floor(Math.random() * 10_000_000_000));
+    const depositAmount = 0.01 * LAMPORTS_PER_SOL;
+    let depositDaoConfigPDA: PublicKey;
+    let depositCreatorMember: PublicKey;
+    let depositProposalPDA: PublicKey;
+
+    before(async () => {
+      depositDaoConfigPDA = getDAOConfigPDA(depositDaoId);
+      depositCreatorMember = getMemberAccount(creator.publicKey, depositDaoConfigPDA);
+      depositProposalPDA = getProposalPDA(new anchor.BN(0), depositDaoConfigPDA);
+
+      await program.methods
+        .initializeDao(depositDaoId, { consensusPct, quorumPct, consensusLifetime, minMultisigSigners, proposalRentDestination, voiceCreditsPerRound, voiceCreditRoundDuration, convictionHalfLife, convictionMinPct, revealLifetime, vetoPeriod, vetoPct, executionDelay, minSponsors, proposalDeposit: new anchor.BN(depositAmount), forfeitDismissedDeposits: true, maxOpenProposalsPerMember, proposalCooldown })
+        .accountsStrict({
+          daoConfig: depositDaoConfigPDA,
+          treasury: getDAOTreasury(depositDaoConfigPDA),
+          creator: creator.publicKey,
+          member: depositCreatorMember,
+          governanceMint: null,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([creator])
+        .rpc();
+    });
+
+    it("Should hold the deposit in escrow and return it to the author of a cancelled proposal", async () => {
+      const depositPDA = getProposalDepositPDA(depositProposalPDA);
+
+      await program.methods
+        .submitProposal("Deposited Proposal", inlineContent, creator.publicKey, new anchor.BN(0), { standard: {} }, [])
+        .accountsStrict({
+          daoConfig: depositDaoConfigPDA,
+          proposal: depositProposalPDA,
+          proposalDeposit: depositPDA,
+          author: creator.publicKey,
+          authorMember: depositCreatorMember,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([creator])
+        .rpc();
+
+      // Verify the deposit was moved into the escrow
+      expect(await provider.connection.getBalance(depositPDA)).to.equal(depositAmount);
+
+      // Verify the proposal counts as open for its author
+      let authorMember = await program.account.member.fetch(depositCreatorMember);
+      expect(authorMember.openProposals.toNumber()).to.equal(1);
+      expect(authorMember.lastProposalAt.toNumber()).to.be.greaterThan(0);
+
+      await program.methods
+        .cancelProposal()
+        .accountsStrict({
+          author: creator.publicKey,
+          daoConfig: depositDaoConfigPDA,
+          authorMember: depositCreatorMember,
+          proposal: depositProposalPDA,
+        })
+        .signers([creator])
+        .rpc();
+
+      // Verify the cancelled proposal no longer counts as open
+      authorMember = await program.account.member.fetch(depositCreatorMember);
+      expect(authorMember.openProposals.toNumber()).to.equal(0);
+
+      await program.methods
+        .resolveProposal(null)
+        .accountsStrict({
+          daoConfig: depositDaoConfigPDA,
+          proposal: depositProposalPDA,
+          authorMember: depositCreatorMember,
+          resolver: creator.publicKey,
+          resolverMember: depositCreatorMember,
+          daoTreasury: getDAOTreasury(depositDaoConfigPDA),
+          proposalTreasury: creator.publicKey,
+          proposalReceipt: getProposalReceiptPDA(depositProposalPDA),
+          rentDestination: creator.publicKey, // The author's wallet
+          proposalDeposit: depositPDA,
+          depositRecipient: creator.publicKey, // Drafts cancelled before voting opened keep their deposit
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([creator])
+        .rpc();
+
+      // Verify the escrow was emptied back into the author's wallet
+      expect(await provider.connection.getBalance(depositPDA)).to.equal(0);
+    });
+  });
+
+  describe("Off-Chain Content", () => {
+    it("Should store off-chain content by URI and record its hash on resolution", async () => {
+      const externalProposalPDA = await getNextProposalPDA();
+      const contentHash = hashProposalContent("# Full specification\n\nStored off-chain, too long to fit in the proposal.");
+
+      await program.methods
+        .submitProposal("Off-Chain Proposal", { external: { uri: "https://example.com/specs/1.md", hash: contentHash } }, creator.publicKey, new anchor.BN(0), { standard: {} }, [])
+        .accountsStrict({
+          daoConfig: daoConfigPDA,
+          proposal: externalProposalPDA,
+          proposalDeposit: getProposalDepositPDA(externalProposalPDA),
+          author: creator.publicKey,
+          authorMember: creatorMemberAccount,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([creator])
+        .rpc();
+
+      const proposal = await program.account.proposal.fetch(externalProposalPDA);
+      expect(proposal.content.external.uri).to.equal("https://example.com/specs/1.md");
+      expect(proposal.content.external.hash).to.deep.equal(contentHash);
+
+      await program.methods
+        .cancelProposal()
+        .accountsStrict({
+          author: creator.publicKey,
+          daoConfig: daoConfigPDA,
+          authorMember: creatorMemberAccount,
+          proposal: externalProposalPDA,
+        })
+        .signers([creator])
+        .rpc();
+
+      await program.methods
+        .resolveProposal(contentHash)
+        .accountsStrict({
+          daoConfig: daoConfigPDA,
+          proposal: externalProposalPDA,
+          authorMember: creatorMemberAccount,
+          resolver: creator.publicKey,
+          resolverMember: creatorMemberAccount,
+          daoTreasury: treasuryPDA,
+          proposalTreasury: creator.publicKey,
+          proposalReceipt: getProposalReceiptPDA(externalProposalPDA),
+          rentDestination: creator.publicKey, // The author's wallet
+          proposalDeposit: getProposalDepositPDA(externalProposalPDA),
+          depositRecipient: creator.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([creator])
+        .rpc();
+
+      // Verify the receipt keeps the content hash after the proposal is closed
+      const receipt = await program.account.proposalReceipt.fetch(getProposalReceiptPDA(externalProposalPDA));
+      expect(receipt.contentHash).to.deep.equal(contentHash);
+    });
+  });
+
+  describe("Relayed Voting", () => {
+    it("Should record a vote signed by a member and relayed by another", async () => {
+      const relayedProposalPDA = await getNextProposalPDA();
+
+      await program.methods
+        .submitProposal("Relayed Vote Proposal", inlineContent, creator.publicKey, new anchor.BN(0), { standard: {} }, [])
+        .accountsStrict({
+          daoConfig: daoConfigPDA,
+          proposal: relayedProposalPDA,
+          proposalDeposit: getProposalDepositPDA(relayedProposalPDA),
+          author: creator.publicKey,
+          authorMember: creatorMemberAccount,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([creator])
+        .rpc();
+
+      await program.methods
+        .openVoting()
+        .accountsStrict({
+          author: creator.publicKey,
+          daoConfig: daoConfigPDA,
+          authorMember: creatorMemberAccount,
+          proposal: relayedProposalPDA,
+        })
+        .signers([creator])
+        .rpc();
+
+      const voterBalanceBefore = await provider.connection.getBalance(person1.publicKey);
+
+      // person1 signs the vote off-chain and person2 relays it
+      const message = getRelayedVoteMessage(daoConfigPDA, relayedProposalPDA, 1, new anchor.BN(0));
+      const verifySignatureIx = Ed25519Program.createInstructionWithPrivateKey({ privateKey: person1.secretKey, message });
+
+      await program.methods
+        .relayVote(1, new anchor.BN(0))
+        .accountsStrict({
+          relayer: person2.publicKey,
+          daoConfig: daoConfigPDA,
+          proposal: relayedProposalPDA,
+          voterMember: person1MemberAccount,
+          vote: getVotePDA(person1MemberAccount, relayedProposalPDA),
+          voterDeposit: null,
+          instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+          systemProgram: SystemProgram.programId,
+        })
+        .preInstructions([verifySignatureIx])
+        .signers([person2])
+        .rpc();
+
+      // Verify the vote was counted for the signer, who paid nothing
+      const proposal = await program.account.proposal.fetch(relayedProposalPDA);
+      expect(proposal.upvotes.toNumber()).to.equal(1);
+
+      const member = await program.account.member.fetch(person1MemberAccount);
+      expect(member.relayNonce.toNumber()).to.equal(1);
+
+      const voterBalanceAfter = await provider.connection.getBalance(person1.publicKey);
+      expect(voterBalanceAfter).to.equal(voterBalanceBefore);
+    });
+  });
+
+  describe("Member Exit", () => {
+    it("Should allow a member to exit the DAO", async () => {
+      const membersCountBefore = (await program.account.daoConfig.fetch(daoConfigPDA)).membersCount;
+      const delegatedVoteRent = (await provider.connection.getAccountInfo(getVotePDA(person3MemberAccount, delegationProposalPDA))).lamports;
+      const delegateBalanceBefore = await provider.connection.getBalance(person1.publicKey);
+
+      await program.methods
+        .exitDao()
+        .accountsStrict({
+          daoConfig: daoConfigPDA,
+          exitingMember: person3MemberAccount,
+          exiter: person3.publicKey,
+          treasury: treasuryPDA,
+          delegation: getDelegationPDA(person3MemberAccount, daoConfigPDA), // person3 still delegates to person1
+          systemProgram: SystemProgram.programId,
+        })
+        .remainingAccounts([
+          { pubkey: delegationProposalPDA, isSigner: false, isWritable: true },
+          { pubkey: getVotePDA(person3MemberAccount, delegationProposalPDA), isSigner: false, isWritable: true },
+          { pubkey: person1.publicKey, isSigner: false, isWritable: true }, // The delegate paid the vote's rent
+        ])
+        .signers([person3])
+        .rpc();
+
+      // Verify member count was updated
+      const daoConfig = await program.account.daoConfig.fetch(daoConfigPDA);
+      expect(daoConfig.membersCount.toString()).to.equal(membersCountBefore.subn(1).toString());
+
+      // Verify member account was closed
+      try {
+        await program.account.member.fetch(person3MemberAccount);
+        expect.fail("Member account should be closed");
+      } catch (error) {
+        expect(error.toString()).to.include("Account does not exist");
+      }
+
+      // Verify the vote person1 cast for person3 was removed from the tally
+      const delegationProposal = await program.account.proposal.fetch(delegationProposalPDA);
+      expect(delegationProposal.upvotes.toString()).to.equal("1");
+      expect(delegationProposal.votersCount.toString()).to.equal("2");
+      try {
+        await program.account.vote.fetch(getVotePDA(person3MemberAccount, delegationProposalPDA));
+        expect.fail("Vote account should be closed");
+      } catch (error) {
+        expect(error.toString()).to.include("Account does not exist");
+      }
+
+      // Verify the rent was refunded to person1, who paid it when casting the vote
+      const delegateBalanceAfter = await provider.connection.getBalance(person1.publicKey);
+      expect(delegateBalanceAfter).to.equal(delegateBalanceBefore + delegatedVoteRent);
+
+      // Verify the delegation was closed along with it
+      try {
+        await program.account.delegation.fetch(getDelegationPDA(person3MemberAccount, daoConfigPDA));
+        expect.fail("Delegation account should be closed");
       } catch (error) {
         expect(error.toString()).to.include("Account does not exist");
       }
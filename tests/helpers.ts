@@ -1,7 +1,18 @@
 import * as anchor from "@coral-xyz/anchor";
 import { Program } from "@coral-xyz/anchor";
 import { Prothesis } from "../target/types/prothesis";
-import { PublicKey, Keypair, LAMPORTS_PER_SOL } from "@solana/web3.js";
+import { PublicKey, Keypair, LAMPORTS_PER_SOL, SystemProgram, Transaction, sendAndConfirmTransaction } from "@solana/web3.js";
+import {
+    createInitializeMintInstruction,
+    createInitializeTransferFeeConfigInstruction,
+    createMint,
+    ExtensionType,
+    getMintLen,
+    getOrCreateAssociatedTokenAccount,
+    mintTo,
+    TOKEN_2022_PROGRAM_ID,
+} from "@solana/spl-token";
+import { createHash } from "crypto";
 
 const { programId } = anchor.workspace.prothesis as Program<Prothesis>;
 
@@ -37,20 +48,20 @@ export function getMemberAccount(owner: PublicKey, daoConfigPDA: PublicKey): Pub
     return memberAccount;
 }
 
-export function getRoleOpPDA(seed: string, nominatedMember: PublicKey, daoConfigPDA: PublicKey): PublicKey {
+export function getRoleOpPDA(seed: string, nominatedMember: PublicKey, daoConfigPDA: PublicKey, roleOpId: anchor.BN): PublicKey {
     const [roleOpPDA] = PublicKey.findProgramAddressSync(
-        [Buffer.from(seed), nominatedMember.toBuffer(), daoConfigPDA.toBuffer()],
+        [Buffer.from(seed), nominatedMember.toBuffer(), daoConfigPDA.toBuffer(), roleOpId.toBuffer("le", 8)],
         programId
     );
     return roleOpPDA;
 }
 
-export function getProposalPDA(title: string, daoConfigPDA: PublicKey): PublicKey {
-    const [roleOpVotePDA] = PublicKey.findProgramAddressSync(
-        [Buffer.from("proposal"), Buffer.from(title), daoConfigPDA.toBuffer()],
+export function getProposalPDA(proposalId: anchor.BN, daoConfigPDA: PublicKey): PublicKey {
+    const [proposalPDA] = PublicKey.findProgramAddressSync(
+        [Buffer.from("proposal"), proposalId.toBuffer("le", 8), daoConfigPDA.toBuffer()],
         programId
     );
-    return roleOpVotePDA;
+    return proposalPDA;
 }
 
 export function getVotePDA(voterMember: PublicKey, targetPDA: PublicKey): PublicKey {
@@ -59,4 +70,178 @@ export function getVotePDA(voterMember: PublicKey, targetPDA: PublicKey): Public
         programId
     );
     return votePDA;
-}
\ No newline at end of file
+}
+
+export function getFundingApprovalPDA(approverMember: PublicKey, proposalPDA: PublicKey): PublicKey {
+    const [fundingApprovalPDA] = PublicKey.findProgramAddressSync(
+        [Buffer.from("funding_approval"), approverMember.toBuffer(), proposalPDA.toBuffer()],
+        programId
+    );
+    return fundingApprovalPDA;
+}
+
+export function getProposalReceiptPDA(proposalPDA: PublicKey): PublicKey {
+    const [proposalReceiptPDA] = PublicKey.findProgramAddressSync(
+        [Buffer.from("proposal_receipt"), proposalPDA.toBuffer()],
+        programId
+    );
+    return proposalReceiptPDA;
+}
+
+export function getRoleOpReceiptPDA(roleOpPDA: PublicKey): PublicKey {
+    const [roleOpReceiptPDA] = PublicKey.findProgramAddressSync(
+        [Buffer.from("role_op_receipt"), roleOpPDA.toBuffer()],
+        programId
+    );
+    return roleOpReceiptPDA;
+}
+
+export function getDelegationPDA(delegatorMember: PublicKey, daoConfigPDA: PublicKey): PublicKey {
+    const [delegationPDA] = PublicKey.findProgramAddressSync(
+        [Buffer.from("delegation"), delegatorMember.toBuffer(), daoConfigPDA.toBuffer()],
+        programId
+    );
+    return delegationPDA;
+}
+
+export function getGovernanceVaultPDA(daoConfigPDA: PublicKey): PublicKey {
+    const [governanceVaultPDA] = PublicKey.findProgramAddressSync(
+        [Buffer.from("governance_vault"), daoConfigPDA.toBuffer()],
+        programId
+    );
+    return governanceVaultPDA;
+}
+
+export function getGovernanceDepositPDA(owner: PublicKey, daoConfigPDA: PublicKey): PublicKey {
+    const [governanceDepositPDA] = PublicKey.findProgramAddressSync(
+        [Buffer.from("governance_deposit"), owner.toBuffer(), daoConfigPDA.toBuffer()],
+        programId
+    );
+    return governanceDepositPDA;
+}
+
+// Creates a governance token mint and mints `amount` tokens to each holder, returning the mint and the holders' token accounts
+export async function createGovernanceMint(
+    provider: anchor.AnchorProvider,
+    mintAuthority: Keypair,
+    holders: { owner: PublicKey, amount: number }[]
+): Promise<{ mint: PublicKey, tokenAccounts: PublicKey[] }> {
+    const mint = await createMint(provider.connection, mintAuthority, mintAuthority.publicKey, null, 0);
+
+    const tokenAccounts: PublicKey[] = [];
+    for (const { owner, amount } of holders) {
+        const tokenAccount = await getOrCreateAssociatedTokenAccount(provider.connection, mintAuthority, mint, owner);
+        await mintTo(provider.connection, mintAuthority, mint, tokenAccount.address, mintAuthority, amount);
+        tokenAccounts.push(tokenAccount.address);
+    }
+
+    return { mint, tokenAccounts };
+}
+
+// Creates a Token-2022 governance token mint charging a transfer fee of `feeBasisPoints`, and mints `amount` tokens to each holder
+export async function createTransferFeeGovernanceMint(
+    provider: anchor.AnchorProvider,
+    mintAuthority: Keypair,
+    feeBasisPoints: number,
+    holders: { owner: PublicKey, amount: number }[]
+): Promise<{ mint: PublicKey, tokenAccounts: PublicKey[] }> {
+    const mintKeypair = Keypair.generate();
+    const mint = mintKeypair.publicKey;
+    const mintLen = getMintLen([ExtensionType.TransferFeeConfig]);
+    const lamports = await provider.connection.getMinimumBalanceForRentExemption(mintLen);
+
+    const transaction = new Transaction().add(
+        SystemProgram.createAccount({
+            fromPubkey: mintAuthority.publicKey,
+            newAccountPubkey: mint,
+            space: mintLen,
+            lamports,
+            programId: TOKEN_2022_PROGRAM_ID,
+        }),
+        createInitializeTransferFeeConfigInstruction(mint, mintAuthority.publicKey, mintAuthority.publicKey, feeBasisPoints, BigInt(Number.MAX_SAFE_INTEGER), TOKEN_2022_PROGRAM_ID),
+        createInitializeMintInstruction(mint, 0, mintAuthority.publicKey, null, TOKEN_2022_PROGRAM_ID),
+    );
+    await sendAndConfirmTransaction(provider.connection, transaction, [mintAuthority, mintKeypair]);
+
+    const tokenAccounts: PublicKey[] = [];
+    for (const { owner, amount } of holders) {
+        const tokenAccount = await getOrCreateAssociatedTokenAccount(provider.connection, mintAuthority, mint, owner, false, undefined, undefined, TOKEN_2022_PROGRAM_ID);
+        await mintTo(provider.connection, mintAuthority, mint, tokenAccount.address, mintAuthority, amount, [], undefined, TOKEN_2022_PROGRAM_ID);
+        tokenAccounts.push(tokenAccount.address);
+    }
+
+    return { mint, tokenAccounts };
+}
+
+export function getVoiceCreditsPDA(member: PublicKey, daoConfigPDA: PublicKey, round: anchor.BN): PublicKey {
+    const [voiceCreditsPDA] = PublicKey.findProgramAddressSync(
+        [Buffer.from("voice_credits"), member.toBuffer(), daoConfigPDA.toBuffer(), round.toBuffer("le", 8)],
+        programId
+    );
+    return voiceCreditsPDA;
+}
+
+export function getVoteCommitmentPDA(voterMember: PublicKey, proposalPDA: PublicKey): PublicKey {
+    const [voteCommitmentPDA] = PublicKey.findProgramAddressSync(
+        [Buffer.from("vote_commitment"), voterMember.toBuffer(), proposalPDA.toBuffer()],
+        programId
+    );
+    return voteCommitmentPDA;
+}
+
+export function hashVoteCommitment(vote: number, salt: Buffer, voterMember: PublicKey, proposalPDA: PublicKey): number[] {
+    return Array.from(
+        createHash("sha256")
+            .update(Buffer.from([vote]))
+            .update(salt)
+            .update(voterMember.toBuffer())
+            .update(proposalPDA.toBuffer())
+            .digest()
+    );
+}
+
+export function hashProposalContent(content: string): number[] {
+    return Array.from(createHash("sha256").update(content).digest());
+}
+
+export function getRelayedVoteMessage(daoConfigPDA: PublicKey, proposalPDA: PublicKey, vote: number, nonce: anchor.BN): Buffer {
+    return Buffer.concat([
+        Buffer.from("prothesis:relayed_vote"),
+        daoConfigPDA.toBuffer(),
+        proposalPDA.toBuffer(),
+        Buffer.from([vote]),
+        nonce.toBuffer("le", 8),
+    ]);
+}
+
+export function getBallotPDA(voterMember: PublicKey, proposalPDA: PublicKey): PublicKey {
+    const [ballotPDA] = PublicKey.findProgramAddressSync(
+        [Buffer.from("ballot"), voterMember.toBuffer(), proposalPDA.toBuffer()],
+        programId
+    );
+    return ballotPDA;
+}
+
+export function getVetoPDA(councilMember: PublicKey, proposalPDA: PublicKey): PublicKey {
+    const [vetoPDA] = PublicKey.findProgramAddressSync(
+        [Buffer.from("veto"), councilMember.toBuffer(), proposalPDA.toBuffer()],
+        programId
+    );
+    return vetoPDA;
+}
+
+export function getProposalDepositPDA(proposalPDA: PublicKey): PublicKey {
+    const [proposalDepositPDA] = PublicKey.findProgramAddressSync(
+        [Buffer.from("proposal_deposit"), proposalPDA.toBuffer()],
+        programId
+    );
+    return proposalDepositPDA;
+}
+
+export function getSponsorshipPDA(sponsorMember: PublicKey, proposalPDA: PublicKey): PublicKey {
+    const [sponsorshipPDA] = PublicKey.findProgramAddressSync(
+        [Buffer.from("sponsorship"), sponsorMember.toBuffer(), proposalPDA.toBuffer()],
+        programId
+    );
+    return sponsorshipPDA;
+}